@@ -0,0 +1,292 @@
+//! Solidity ABI encoding of calldata and decoding of results, for the typed bindings of the
+//! translated contracts.
+//!
+//! The encoding is the one of `alloy-sol-types`, with the `uintN` and `intN` types all
+//! represented by `U256` and `I256`.
+
+use crate::{CallOutcome, ExecutionResult, Halt, Memory, ReturnOrRevert};
+use alloy_primitives::{hex, keccak256, Log, B256, U256};
+use alloy_sol_types::abi::TokenSeq;
+use alloy_sol_types::{SolType, SolValue};
+use std::fmt;
+
+/// A tuple of values with a Solidity ABI encoding, as the parameters of a function.
+pub trait AbiType: Sized {
+    fn encode(&self) -> Vec<u8>;
+
+    /// Decode, checking that the values are valid for their types.
+    fn decode(data: &[u8]) -> Option<Self>;
+}
+
+impl<T> AbiType for T
+where
+    T: SolValue + From<<T::SolType as SolType>::RustType>,
+    for<'a> <T::SolType as SolType>::Token<'a>: TokenSeq<'a>,
+{
+    fn encode(&self) -> Vec<u8> {
+        self.abi_encode_params()
+    }
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        Self::abi_decode_params_validate(data).ok()
+    }
+}
+
+/// The first four bytes of the hash of a function or error signature, like
+/// `transfer(address,uint256)`.
+pub fn selector(signature: &str) -> [u8; 4] {
+    keccak256(signature)[..4].try_into().unwrap()
+}
+
+pub fn encode<T: AbiType>(value: &T) -> Vec<u8> {
+    value.encode()
+}
+
+pub fn decode<T: AbiType>(data: &[u8]) -> Option<T> {
+    T::decode(data)
+}
+
+/// Calldata of a call to the function with the given signature, with a tuple of arguments.
+pub fn encode_call<T: AbiType>(signature: &str, arguments: &T) -> Vec<u8> {
+    [selector(signature).as_slice(), &encode(arguments)].concat()
+}
+
+/// Decode the arguments of an error if `data` starts with the selector of `signature`.
+pub fn decode_error<T: AbiType>(signature: &str, data: &[u8]) -> Option<T> {
+    let arguments = data.strip_prefix(&selector(signature))?;
+    decode(arguments)
+}
+
+/// Signature of the errors of `require` and `revert` with a message.
+pub const ERROR_SIGNATURE: &str = "Error(string)";
+/// Signature of the errors of the checks inserted by the Solidity compiler.
+pub const PANIC_SIGNATURE: &str = "Panic(uint256)";
+
+/// Code of a `Panic(uint256)` error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PanicCode(pub U256);
+
+impl PanicCode {
+    /// What the code means, as documented by Solidity.
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match u8::try_from(self.0).ok()? {
+            0x00 => "generic compiler panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "invalid storage byte array encoding",
+            0x31 => "pop on an empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "too much memory allocated",
+            0x51 => "call to a zero-initialized internal function",
+            _ => return None,
+        };
+        Some(description)
+    }
+}
+
+impl fmt::Display for PanicCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.description() {
+            Some(description) => write!(f, "{description} ({:#04x})", self.0),
+            None => write!(f, "unknown panic ({:#x})", self.0),
+        }
+    }
+}
+
+/// The reason of a revert, decoded from its data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RevertReason {
+    Error(String),
+    Panic(PanicCode),
+    /// A custom error among the given signatures, with its encoded arguments.
+    Custom {
+        signature: String,
+        arguments: Vec<u8>,
+    },
+    /// Data which is not a known error, like the empty data of `revert(0, 0)`.
+    Unknown(Vec<u8>),
+}
+
+impl RevertReason {
+    /// Classify revert data, looking for the custom errors in `errors`, a list of signatures like
+    /// `ERC20InsufficientBalance(address,uint256,uint256)`.
+    pub fn decode(data: &[u8], errors: &[&str]) -> Self {
+        if let Some((message,)) = decode_error(ERROR_SIGNATURE, data) {
+            return RevertReason::Error(message);
+        }
+        if let Some((code,)) = decode_error(PANIC_SIGNATURE, data) {
+            return RevertReason::Panic(PanicCode(code));
+        }
+        for signature in errors {
+            if let Some(arguments) = data.strip_prefix(&selector(signature)) {
+                return RevertReason::Custom {
+                    signature: signature.to_string(),
+                    arguments: arguments.to_vec(),
+                };
+            }
+        }
+        RevertReason::Unknown(data.to_vec())
+    }
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RevertReason::Error(message) => write!(f, "{message}"),
+            RevertReason::Panic(code) => write!(f, "panic: {code}"),
+            RevertReason::Custom {
+                signature,
+                arguments,
+            } => write!(f, "{signature} with arguments 0x{}", hex::encode(arguments)),
+            RevertReason::Unknown(data) if data.is_empty() => write!(f, "revert without data"),
+            RevertReason::Unknown(data) => write!(f, "unknown revert data 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+impl ReturnOrRevert {
+    /// Read the data of a revert in the `memory` of the frame, and decode it. Returns `None` for
+    /// the other results.
+    pub fn revert_reason(&self, memory: &Memory, errors: &[&str]) -> Option<RevertReason> {
+        match self {
+            ReturnOrRevert::Revert { start, length } => {
+                let data = memory.load(*start, *length).ok()?;
+                Some(RevertReason::decode(&data, errors))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl CallOutcome {
+    pub fn revert_reason(&self, errors: &[&str]) -> Option<RevertReason> {
+        match self {
+            CallOutcome::Revert(data) => Some(RevertReason::decode(data, errors)),
+            _ => None,
+        }
+    }
+}
+
+impl ExecutionResult {
+    pub fn revert_reason(&self, errors: &[&str]) -> Option<RevertReason> {
+        (!self.success && self.halt.is_none()).then(|| RevertReason::decode(&self.output, errors))
+    }
+}
+
+/// The custom errors of a contract, decoded from revert data.
+pub trait CustomError: Sized {
+    fn decode(data: &[u8]) -> Option<Self>;
+}
+
+/// The first topic of the logs of an event, given its signature like
+/// `Transfer(address,address,uint256)`.
+pub fn event_topic(signature: &str) -> B256 {
+    keccak256(signature)
+}
+
+/// Decode the indexed argument of an event in the topic at `index`. The indexed arguments of
+/// dynamic types are only available as their hash, of type `B256`.
+pub fn decode_topic<T>(log: &Log, index: usize) -> Option<T>
+where
+    (T,): AbiType,
+{
+    decode(log.topics().get(index)?.as_slice()).map(|(value,)| value)
+}
+
+/// The events of a contract, decoded from its logs.
+pub trait Event: Sized {
+    fn decode_log(log: &Log) -> Option<Self>;
+
+    /// The logs which are events of the contract, in order.
+    fn decode_logs(logs: &[Log]) -> Vec<Self> {
+        logs.iter().filter_map(Self::decode_log).collect()
+    }
+}
+
+/// Why a typed call to a contract did not return a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Revert<E> {
+    Error(String),
+    Panic(PanicCode),
+    /// One of the custom errors of the contract.
+    Custom(E),
+    /// Revert data which is not a known error.
+    Data(Vec<u8>),
+    Halt(Halt),
+    /// The call succeeded but its output is not of the expected type.
+    InvalidOutput(Vec<u8>),
+}
+
+/// Decode the returned values of a call, or the reason why it failed.
+pub fn decode_result<T: AbiType, E: CustomError>(result: ExecutionResult) -> Result<T, Revert<E>> {
+    if let Some(halt) = result.halt {
+        return Err(Revert::Halt(halt));
+    }
+    if !result.success {
+        if let Some(error) = E::decode(&result.output) {
+            return Err(Revert::Custom(error));
+        }
+        return Err(match RevertReason::decode(&result.output, &[]) {
+            RevertReason::Error(message) => Revert::Error(message),
+            RevertReason::Panic(code) => Revert::Panic(code),
+            _ => Revert::Data(result.output),
+        });
+    }
+    decode(&result.output).ok_or(Revert::InvalidOutput(result.output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, Address, Bytes};
+
+    #[test]
+    fn call_with_static_arguments() {
+        let to = address!("5FbDB2315678afecb367f032d93F642f64180aa3");
+        let calldata = encode_call("transfer(address,uint256)", &(to, U256::from(1000)));
+        assert_eq!(
+            hex::encode(calldata),
+            "a9059cbb\
+             0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3\
+             00000000000000000000000000000000000000000000000000000000000003e8"
+        );
+    }
+
+    #[test]
+    fn round_trip_of_dynamic_values() {
+        let value = (
+            U256::from(1),
+            "hello".to_string(),
+            vec![Bytes::from(vec![1, 2, 3]), Bytes::new()],
+            [Address::ZERO; 2],
+        );
+        assert_eq!(decode(&encode(&value)), Some(value));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        let mut data = encode(&(true,));
+        data[0] = 1;
+        assert_eq!(decode::<(bool,)>(&data), None);
+        assert_eq!(decode::<(Address,)>(&[0xff; 32]), None);
+        assert_eq!(decode::<(U256,)>(&[0; 31]), None);
+    }
+
+    #[test]
+    fn revert_reasons() {
+        let data = encode_call(ERROR_SIGNATURE, &("no".to_string(),));
+        assert_eq!(
+            RevertReason::decode(&data, &[]),
+            RevertReason::Error("no".to_string())
+        );
+        let data = encode_call(PANIC_SIGNATURE, &(U256::from(0x11),));
+        let reason = RevertReason::decode(&data, &[]);
+        assert_eq!(reason.to_string(), "panic: arithmetic overflow (0x11)");
+        assert_eq!(
+            RevertReason::decode(&[], &[]),
+            RevertReason::Unknown(vec![])
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use alloy_primitives::U256;
+use std::collections::HashMap;
+
+/// Number of previous blocks whose hash is available to `blockhash`.
+pub const BLOCK_HASH_HISTORY: u64 = 256;
+
+/// The block in which the transaction runs.
+#[derive(Clone, Debug, Default)]
+pub struct BlockEnv {
+    pub number: U256,
+    pub coinbase: U256,
+    pub timestamp: U256,
+    pub gas_limit: U256,
+    pub basefee: U256,
+    pub blob_basefee: U256,
+    /// Also returned by `difficulty` since the merge.
+    pub prevrandao: U256,
+    pub chain_id: U256,
+    /// Hashes of the previous blocks by number. Only the last `BLOCK_HASH_HISTORY` ones are
+    /// visible, and missing ones are zero.
+    pub block_hashes: HashMap<U256, U256>,
+}
+
+impl BlockEnv {
+    /// The hash of a previous block, as returned by `blockhash`.
+    pub fn block_hash(&self, number: U256) -> U256 {
+        let is_visible =
+            number < self.number && self.number - number <= U256::from(BLOCK_HASH_HISTORY);
+        if !is_visible {
+            return U256::ZERO;
+        }
+        self.block_hashes.get(&number).cloned().unwrap_or_default()
+    }
+}
+
+/// The transaction being run.
+#[derive(Clone, Debug, Default)]
+pub struct TxEnv {
+    pub origin: U256,
+    pub gas_price: U256,
+    /// Versioned hashes of the blobs of the transaction (EIP-4844).
+    pub blob_hashes: Vec<U256>,
+}
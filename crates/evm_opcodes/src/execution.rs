@@ -0,0 +1,130 @@
+use crate::gas;
+use crate::journal::JournalEntry;
+use crate::{CallOutcome, Code, Context, Gas, Halt, Memory, ReturnOrRevert, StorageSlot, World};
+use alloy_primitives::{Log, U256};
+use std::collections::HashMap;
+
+/// Changes of an account during a transaction, with the values before and after it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountDiff {
+    pub balance: Option<(U256, U256)>,
+    pub nonce: Option<(u64, u64)>,
+    pub storage: HashMap<U256, StorageSlot>,
+    /// Whether a contract was deployed at this address.
+    pub created: bool,
+}
+
+/// The accounts modified by a transaction, by address.
+pub type StateDiff = HashMap<U256, AccountDiff>;
+
+/// The result of running a transaction.
+#[derive(Clone, Debug)]
+pub struct ExecutionResult {
+    pub success: bool,
+    /// The returned data, or the revert data on failure.
+    pub output: Vec<u8>,
+    /// Set when the execution failed with an exceptional halt.
+    pub halt: Option<Halt>,
+    pub logs: Vec<Log>,
+    /// Gas used including the intrinsic gas, after the refunds.
+    pub gas_used: u64,
+    pub state_diff: StateDiff,
+}
+
+impl<CI> World<CI> {
+    /// The changes made since the start of the transaction.
+    pub fn state_diff(&self) -> StateDiff {
+        let mut diff = StateDiff::new();
+        // The first change of each value gives its value at the start of the transaction
+        for entry in &self.journal {
+            match *entry {
+                JournalEntry::BalanceChanged { address, previous } => {
+                    let account = diff.entry(address).or_default();
+                    account.balance.get_or_insert((previous, previous));
+                }
+                JournalEntry::NonceChanged { address, previous } => {
+                    let account = diff.entry(address).or_default();
+                    account.nonce.get_or_insert((previous, previous));
+                }
+                JournalEntry::CodeDeployed(address) => {
+                    diff.entry(address).or_default().created = true
+                }
+                _ => {}
+            }
+        }
+        for ((address, key), slot) in self.dirty_slots() {
+            diff.entry(address).or_default().storage.insert(key, slot);
+        }
+        for (&address, account) in diff.iter_mut() {
+            if let Some((_, current)) = &mut account.balance {
+                *current = self.balance(address);
+            }
+            if let Some((_, current)) = &mut account.nonce {
+                *current = self.nonce(address);
+            }
+            account
+                .balance
+                .take_if(|(original, current)| original == current);
+            account
+                .nonce
+                .take_if(|(original, current)| original == current);
+        }
+        diff.retain(|_, account| account != &AccountDiff::default());
+        diff
+    }
+}
+
+impl<CI> Context<CI> {
+    /// Run `code` as a transaction to the current contract, with the given calldata. The gas
+    /// limit of the transaction is the one of `gas`, and `callvalue` is moved from `caller` to
+    /// `address`.
+    pub fn execute(&mut self, code: Code<CI>, calldata: Vec<u8>) -> ExecutionResult {
+        self.world.start_transaction();
+        self.world.access_address(self.caller);
+        self.world.access_address(self.address);
+        self.memory = Memory::new();
+        self.returndata = vec![];
+        self.gas = if self.gas.metered {
+            Gas::metered(self.gas.limit)
+        } else {
+            Gas::unmetered(self.gas.limit)
+        };
+        let intrinsic_gas = gas::intrinsic_gas(&calldata);
+        self.calldata = calldata;
+        let result = self.gas.charge(intrinsic_gas).and_then(|()| {
+            // A transaction with more value than the balance of the caller fails without running
+            if !self
+                .world
+                .transfer(self.caller, self.address, self.callvalue)
+            {
+                return Err(ReturnOrRevert::Revert {
+                    start: U256::ZERO,
+                    length: U256::ZERO,
+                });
+            }
+            code(self)
+        });
+
+        let outcome = CallOutcome::from_result(result, &self.memory);
+        let refund = if outcome.is_success() {
+            self.world.finish_transaction();
+            self.gas.final_refund()
+        } else {
+            self.world.revert_to(0);
+            0
+        };
+        // Transient storage only lasts for the transaction (EIP-1153)
+        self.world.transient_storage.clear();
+        ExecutionResult {
+            success: outcome.is_success(),
+            output: outcome.output().to_vec(),
+            halt: match outcome {
+                CallOutcome::Halt(halt) => Some(halt),
+                _ => None,
+            },
+            logs: self.world.logs.clone(),
+            gas_used: self.gas.used() - refund,
+            state_diff: self.world.state_diff(),
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Gas accounting, following the Cancun schedule.
+//!
+//! Only the opcodes that appear in the Yul code are charged. The stack manipulations and jumps
+//! that the Solidity compiler would add around them (`PUSH`, `DUP`, `JUMP`, ...) do not exist in
+//! the translation, so the gas used is a lower bound of the on-chain one.
+
+use crate::{Halt, ReturnOrRevert, YulOutput};
+use alloy_primitives::U256;
+
+pub const ZERO: u64 = 0;
+pub const BASE: u64 = 2;
+pub const VERYLOW: u64 = 3;
+pub const LOW: u64 = 5;
+pub const MID: u64 = 8;
+pub const BLOCKHASH: u64 = 20;
+pub const EXP: u64 = 10;
+pub const EXP_BYTE: u64 = 50;
+pub const KECCAK256: u64 = 30;
+pub const KECCAK256_WORD: u64 = 6;
+pub const COPY_WORD: u64 = 3;
+pub const MEMORY_WORD: u64 = 3;
+pub const LOG: u64 = 375;
+pub const LOG_TOPIC: u64 = 375;
+pub const LOG_DATA_BYTE: u64 = 8;
+pub const WARM_ACCESS: u64 = 100;
+pub const COLD_SLOAD: u64 = 2100;
+pub const COLD_ACCOUNT_ACCESS: u64 = 2600;
+pub const SSTORE_SET: u64 = 20000;
+pub const SSTORE_RESET: u64 = 5000 - COLD_SLOAD;
+pub const SSTORE_CLEARS_SCHEDULE: i64 = 4800;
+/// The refund of a transaction is at most the gas used divided by this quotient (EIP-3529).
+pub const MAX_REFUND_QUOTIENT: u64 = 5;
+pub const CALL_VALUE: u64 = 9000;
+pub const CALL_STIPEND: u64 = 2300;
+pub const NEW_ACCOUNT: u64 = 25000;
+pub const CREATE: u64 = 32000;
+pub const INITCODE_WORD: u64 = 2;
+pub const CODE_DEPOSIT_BYTE: u64 = 200;
+pub const SELFDESTRUCT: u64 = 5000;
+pub const TX: u64 = 21000;
+pub const TX_DATA_ZERO: u64 = 4;
+pub const TX_DATA_NON_ZERO: u64 = 16;
+
+/// Gas accounting of a call frame.
+#[derive(Clone, Copy, Debug)]
+pub struct Gas {
+    /// Gas given to the frame.
+    pub limit: u64,
+    /// Gas left, as returned by the `gas` opcode.
+    pub remaining: u64,
+    /// When not set, nothing is charged and `remaining` stays at `limit`.
+    pub metered: bool,
+    /// Refund counter, which can be temporarily negative in a sub-call.
+    pub refunded: i64,
+}
+
+impl Gas {
+    pub fn metered(limit: u64) -> Self {
+        Self {
+            limit,
+            remaining: limit,
+            metered: true,
+            refunded: 0,
+        }
+    }
+
+    pub fn unmetered(limit: u64) -> Self {
+        Self {
+            metered: false,
+            ..Self::metered(limit)
+        }
+    }
+
+    pub fn used(&self) -> u64 {
+        self.limit - self.remaining
+    }
+
+    /// The refund given back at the end of a transaction, capped by EIP-3529.
+    pub fn final_refund(&self) -> u64 {
+        (self.refunded.max(0) as u64).min(self.used() / MAX_REFUND_QUOTIENT)
+    }
+
+    pub fn charge(&mut self, cost: u64) -> YulOutput<()> {
+        if !self.metered {
+            return Ok(());
+        }
+        match self.remaining.checked_sub(cost) {
+            Some(remaining) => {
+                self.remaining = remaining;
+                Ok(())
+            }
+            None => {
+                self.remaining = 0;
+                Err(ReturnOrRevert::Halt(Halt::OutOfGas))
+            }
+        }
+    }
+
+    /// Take the gas given to a sub-call. When metered, the callee gets at most all but one 64th
+    /// of the remaining gas (EIP-150). When not, it gets at most the limit of the caller, which
+    /// also bounds its default memory limit.
+    pub fn take_call_gas(&mut self, requested: U256) -> u64 {
+        let requested = as_u64_saturated!(requested);
+        if !self.metered {
+            return requested.min(self.limit);
+        }
+        let gas = requested.min(self.remaining - self.remaining / 64);
+        self.remaining -= gas;
+        gas
+    }
+}
+
+/// Number of words needed to hold `size` bytes.
+pub fn words(size: usize) -> u64 {
+    size.div_ceil(32) as u64
+}
+
+fn words_u256(size: U256) -> u64 {
+    as_u64_saturated!(size).div_ceil(32)
+}
+
+/// Total cost of a memory of `words` words.
+pub fn memory_cost(words: u64) -> u64 {
+    MEMORY_WORD
+        .saturating_mul(words)
+        .saturating_add(words.saturating_mul(words) / 512)
+}
+
+/// Size in bytes of the largest memory which costs at most `gas`.
+pub fn max_memory_size(gas: u64) -> usize {
+    // Largest solution of `3 * words + words * words / 512 <= gas`
+    let words = ((1536u128 * 1536 + 2048 * gas as u128).isqrt() - 1536) / 2;
+    usize::try_from(words * 32).unwrap_or(usize::MAX)
+}
+
+/// Cost of copying `size` bytes to memory, without the memory expansion.
+pub fn copy_cost(size: U256) -> u64 {
+    VERYLOW.saturating_add(COPY_WORD.saturating_mul(words_u256(size)))
+}
+
+pub fn exp_cost(exponent: U256) -> u64 {
+    EXP + EXP_BYTE * exponent.byte_len() as u64
+}
+
+pub fn keccak256_cost(size: U256) -> u64 {
+    KECCAK256.saturating_add(KECCAK256_WORD.saturating_mul(words_u256(size)))
+}
+
+pub fn log_cost(topics: u64, size: U256) -> u64 {
+    (LOG + LOG_TOPIC * topics).saturating_add(LOG_DATA_BYTE.saturating_mul(as_u64_saturated!(size)))
+}
+
+/// Cost of accessing an account, depending on whether it was already accessed (EIP-2929).
+pub fn account_access_cost(is_warm: bool) -> u64 {
+    if is_warm {
+        WARM_ACCESS
+    } else {
+        COLD_ACCOUNT_ACCESS
+    }
+}
+
+pub fn sload_cost(is_warm: bool) -> u64 {
+    if is_warm {
+        WARM_ACCESS
+    } else {
+        COLD_SLOAD
+    }
+}
+
+/// Cost of a `sstore` (EIP-2200 with the access costs of EIP-2929), where `original` is the value
+/// of the slot at the start of the transaction.
+pub fn sstore_cost(is_warm: bool, original: U256, current: U256, new: U256) -> u64 {
+    let cold = if is_warm { 0 } else { COLD_SLOAD };
+    cold + if current == new || original != current {
+        WARM_ACCESS
+    } else if original.is_zero() {
+        SSTORE_SET
+    } else {
+        SSTORE_RESET
+    }
+}
+
+/// Change of the refund counter for a `sstore` (EIP-2200 with the refunds of EIP-3529).
+pub fn sstore_refund(original: U256, current: U256, new: U256) -> i64 {
+    if current == new {
+        return 0;
+    }
+    if original == current {
+        return if !original.is_zero() && new.is_zero() {
+            SSTORE_CLEARS_SCHEDULE
+        } else {
+            0
+        };
+    }
+    let mut refund = 0;
+    if !original.is_zero() {
+        if current.is_zero() {
+            refund -= SSTORE_CLEARS_SCHEDULE;
+        } else if new.is_zero() {
+            refund += SSTORE_CLEARS_SCHEDULE;
+        }
+    }
+    // Restoring the original value refunds what was paid for the first write
+    if original == new {
+        let first_write = if original.is_zero() {
+            SSTORE_SET
+        } else {
+            SSTORE_RESET
+        };
+        refund += (first_write - WARM_ACCESS) as i64;
+    }
+    refund
+}
+
+/// Cost of a `create` with an init code of `size` bytes (EIP-3860).
+pub fn create_cost(size: U256) -> u64 {
+    CREATE.saturating_add(INITCODE_WORD.saturating_mul(words_u256(size)))
+}
+
+/// Cost of a `create2`, which also hashes the init code to compute the address.
+pub fn create2_cost(size: U256) -> u64 {
+    create_cost(size).saturating_add(KECCAK256_WORD.saturating_mul(words_u256(size)))
+}
+
+/// Cost of a `selfdestruct`, with the access of the beneficiary (EIP-2929) and the creation of
+/// the beneficiary account if it receives a balance while empty.
+pub fn selfdestruct_cost(is_warm: bool, creates_account: bool) -> u64 {
+    let cold = if is_warm { 0 } else { COLD_ACCOUNT_ACCESS };
+    let new_account = if creates_account { NEW_ACCOUNT } else { 0 };
+    SELFDESTRUCT + cold + new_account
+}
+
+/// Gas charged before running a transaction with the given calldata.
+pub fn intrinsic_gas(calldata: &[u8]) -> u64 {
+    calldata.iter().fold(TX, |gas, byte| {
+        gas + if *byte == 0 {
+            TX_DATA_ZERO
+        } else {
+            TX_DATA_NON_ZERO
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cost and refund of a sequence of `sstore` to a warm slot with the `original` value.
+    fn sstores(original: u64, values: &[u64]) -> (u64, i64) {
+        let original = U256::from(original);
+        let mut current = original;
+        let (mut cost, mut refund) = (0, 0);
+        for &new in values {
+            let new = U256::from(new);
+            cost += sstore_cost(true, original, current, new);
+            refund += sstore_refund(original, current, new);
+            current = new;
+        }
+        (cost, refund)
+    }
+
+    /// The test cases of EIP-3529, without the gas of the `push` instructions.
+    #[test]
+    fn sstore_cost_and_refund() {
+        let cases: [(u64, &[u64], u64, i64); 17] = [
+            (0, &[0, 0], 212, 0),
+            (0, &[0, 1], 20112, 0),
+            (0, &[1, 0], 20112, 19900),
+            (0, &[1, 2], 20112, 0),
+            (0, &[1, 1], 20112, 0),
+            (1, &[0, 0], 3012, 4800),
+            (1, &[0, 1], 3012, 2800),
+            (1, &[0, 2], 3012, 0),
+            (1, &[2, 0], 3012, 4800),
+            (1, &[2, 3], 3012, 0),
+            (1, &[2, 1], 3012, 2800),
+            (1, &[2, 2], 3012, 0),
+            (1, &[1, 0], 3012, 4800),
+            (1, &[1, 2], 3012, 0),
+            (1, &[1, 1], 212, 0),
+            (0, &[1, 0, 1], 40118, 19900),
+            (1, &[0, 1, 0], 5918, 7600),
+        ];
+        for (original, values, gas_used, refund) in cases {
+            let pushes = 2 * VERYLOW * values.len() as u64;
+            assert_eq!(
+                sstores(original, values),
+                (gas_used - pushes, refund),
+                "original {original}, values {values:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn unmetered_call_gas() {
+        let mut gas = Gas::unmetered(100_000);
+        assert_eq!(gas.take_call_gas(U256::MAX), 100_000);
+        assert_eq!(gas.take_call_gas(U256::from(5000)), 5000);
+        assert_eq!(gas.remaining, 100_000);
+    }
+
+    #[test]
+    fn sstore_cold_slot() {
+        let (zero, one) = (U256::ZERO, U256::from(1));
+        assert_eq!(sstore_cost(false, zero, zero, one), COLD_SLOAD + SSTORE_SET);
+        assert_eq!(sstore_cost(false, one, one, one), COLD_SLOAD + WARM_ACCESS);
+    }
+}
@@ -0,0 +1,276 @@
+//! Pre-state of the accounts from an `alloc` JSON object, as in the genesis files of the
+//! Ethereum clients:
+//!
+//! ```json
+//! {
+//!   "0x5FbDB2315678afecb367f032d93F642f64180aa3": {
+//!     "balance": "0xde0b6b3a7640000",
+//!     "nonce": "0x1",
+//!     "object": "ERC20_514_deployed",
+//!     "immutables": { "64": "0x…" },
+//!     "storage": {
+//!       "0x0000000000000000000000000000000000000000000000000000000000000002": "0x…"
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! As the EVM bytecode cannot run, the code of a contract is given by `object`, the Yul name of
+//! its translated object. It must have been registered by the `register` function of the
+//! generated crate. All the fields are optional, and the unknown ones are ignored.
+
+use crate::storage::{as_u256, invalid, parse_address, parse_immutables, parse_slots};
+use crate::world::Code;
+use crate::World;
+use alloy_primitives::{B256, U256};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/// An account of an `alloc` object, checked before changing the world.
+struct AllocAccount<CI> {
+    address: U256,
+    balance: Option<U256>,
+    nonce: Option<u64>,
+    /// The code and bytes of the object.
+    object: Option<(Code<CI>, Vec<u8>)>,
+    /// The immutables of the code, only set with an object.
+    immutables: HashMap<U256, U256>,
+    storage: Option<Vec<(U256, U256)>>,
+}
+
+impl<CI> World<CI> {
+    /// Add the accounts of an `alloc` JSON object, or of a genesis file with such an object as
+    /// its `alloc` field. The balances, nonces and storage slots of an existing account are
+    /// replaced by the given ones. Nothing is changed if any of the accounts is invalid.
+    pub fn load_alloc(&mut self, text: &str) -> io::Result<()> {
+        let json: Value = serde_json::from_str(text)?;
+        let accounts = json
+            .get("alloc")
+            .unwrap_or(&json)
+            .as_object()
+            .ok_or_else(|| invalid("expected an object of accounts"))?
+            .iter()
+            .map(|(address, account)| {
+                let address = parse_address(address)
+                    .ok_or_else(|| invalid(format!("invalid address {address}")))?;
+                self.parse_account(address, account)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        for account in accounts {
+            self.load_account(account);
+        }
+        Ok(())
+    }
+
+    pub fn load_alloc_file(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.load_alloc(&std::fs::read_to_string(path)?)
+    }
+
+    fn parse_account(&self, address: U256, json: &Value) -> io::Result<AllocAccount<CI>> {
+        let field = |name: &str| json.get(name);
+        let invalid_field = |name: &str| invalid(format!("invalid {name} of {address:#x}"));
+        if json.as_object().is_none() {
+            return Err(invalid_field("account"));
+        }
+
+        let balance = field("balance")
+            .map(|balance| as_u256(balance).ok_or_else(|| invalid_field("balance")))
+            .transpose()?;
+        let nonce = field("nonce")
+            .map(|nonce| {
+                as_u256(nonce)
+                    .and_then(|nonce| u64::try_from(nonce).ok())
+                    .ok_or_else(|| invalid_field("nonce"))
+            })
+            .transpose()?;
+        let object = field("object")
+            .map(|name| {
+                name.as_str()
+                    .and_then(object_name)
+                    .and_then(|name| self.objects.get(&name))
+                    .ok_or_else(|| invalid(format!("unknown object for {address:#x}: {name}")))
+            })
+            .transpose()?;
+        // Some EVM bytecode without its translation
+        let has_bytecode = field("code")
+            .and_then(Value::as_str)
+            .is_some_and(|code| !code.trim_start_matches("0x").is_empty());
+        if object.is_none() && has_bytecode {
+            return Err(invalid(format!(
+                "no translated object for the code of {address:#x}"
+            )));
+        }
+        let immutables = field("immutables")
+            .map(|immutables| parse_immutables(address, immutables))
+            .transpose()?
+            .unwrap_or_default();
+        let storage = field("storage")
+            .map(|slots| parse_slots(address, slots))
+            .transpose()?;
+
+        Ok(AllocAccount {
+            address,
+            balance,
+            nonce,
+            object: object.map(|object| (object.code, object.bytes.clone())),
+            immutables,
+            storage,
+        })
+    }
+
+    fn load_account(&mut self, alloc: AllocAccount<CI>) {
+        let address = alloc.address;
+        if let Some(slots) = alloc.storage {
+            self.storage.clear(address);
+            for (key, value) in slots {
+                self.storage.set(address, key, value);
+            }
+        }
+        let account = self.account_mut(address);
+        if let Some(balance) = alloc.balance {
+            account.balance = balance;
+        }
+        if let Some(nonce) = alloc.nonce {
+            account.nonce = nonce;
+        }
+        if let Some((code, bytecode)) = alloc.object {
+            account.code = Some(code);
+            account.bytecode = bytecode;
+            self.storage.set_immutables(address, alloc.immutables);
+        }
+    }
+}
+
+/// A Yul name, padded to 32 bytes as in `datasize` and `loadimmutable`.
+fn object_name(name: &str) -> Option<U256> {
+    (name.len() <= 32).then(|| B256::right_padding_from(name.as_bytes()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, WorldInteractions, YulOutput};
+
+    const TOKEN: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+
+    fn token(_context: &mut Context<WorldInteractions>) -> YulOutput<()> {
+        Ok(())
+    }
+
+    fn world() -> World<WorldInteractions> {
+        let mut world = World::new();
+        world.register_object(object_name("Token_deployed").unwrap(), token, vec![]);
+        world
+    }
+
+    fn address() -> U256 {
+        parse_address(TOKEN).unwrap()
+    }
+
+    #[test]
+    fn registered_object() {
+        let mut world = world();
+        world
+            .load_alloc(&format!(
+                r#"{{
+                    "{TOKEN}": {{
+                        "balance": "0xde0b6b3a7640000",
+                        "nonce": 1,
+                        "object": "Token_deployed",
+                        "immutables": {{ "64": "0x2a" }},
+                        "storage": {{ "0x02": "0000000000000000000000000000000000000000000000000000000000000010" }}
+                    }}
+                }}"#
+            ))
+            .unwrap();
+
+        let account = world.account(address()).unwrap();
+        assert!(account.code.is_some());
+        assert_eq!(
+            account.bytecode,
+            world.objects[&object_name("Token_deployed").unwrap()].bytes
+        );
+        assert_eq!(world.balance(address()), U256::from(10u64.pow(18)));
+        assert_eq!(world.nonce(address()), 1);
+        assert_eq!(world.sload(address(), U256::from(2)), U256::from(0x10));
+        assert_eq!(
+            world.storage.immutables(address()),
+            HashMap::from([(object_name("64").unwrap(), U256::from(42))])
+        );
+    }
+
+    #[test]
+    fn unknown_object() {
+        let mut world = world();
+        let error = world
+            .load_alloc(&format!(
+                r#"{{ "{TOKEN}": {{ "object": "Vault_deployed" }} }}"#
+            ))
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(world.account(address()).is_none());
+    }
+
+    #[test]
+    fn code_without_object() {
+        let mut world = world();
+        let error = world
+            .load_alloc(&format!(r#"{{ "{TOKEN}": {{ "code": "0x6080" }} }}"#))
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        // An empty code is the one of an account without a contract
+        world
+            .load_alloc(&format!(
+                r#"{{ "{TOKEN}": {{ "code": "0x", "balance": "1" }} }}"#
+            ))
+            .unwrap();
+        assert!(world.account(address()).unwrap().code.is_none());
+    }
+
+    #[test]
+    fn storage_replaces_the_existing_slots() {
+        let mut world = world();
+        world.storage.set(address(), U256::from(1), U256::from(1));
+        world.storage.set(address(), U256::from(2), U256::from(2));
+        world.account_mut(address()).balance = U256::from(5);
+        world
+            .load_alloc(&format!(
+                r#"{{ "{TOKEN}": {{ "storage": {{ "0x02": "0x03" }} }} }}"#
+            ))
+            .unwrap();
+
+        assert_eq!(world.sload(address(), U256::from(1)), U256::ZERO);
+        assert_eq!(world.sload(address(), U256::from(2)), U256::from(3));
+        assert_eq!(world.balance(address()), U256::from(5));
+    }
+
+    #[test]
+    fn genesis_file() {
+        let mut world = world();
+        world
+            .load_alloc(&format!(
+                r#"{{ "config": {{ "chainId": 1 }}, "alloc": {{ "{TOKEN}": {{ "balance": "0x10" }} }} }}"#
+            ))
+            .unwrap();
+        assert_eq!(world.balance(address()), U256::from(0x10));
+    }
+
+    #[test]
+    fn invalid_account_changes_nothing() {
+        let mut world = world();
+        world.storage.set(address(), U256::from(1), U256::from(1));
+        let error = world
+            .load_alloc(&format!(
+                r#"{{
+                    "{TOKEN}": {{ "balance": "0x10", "storage": {{}} }},
+                    "0xinvalid": {{ "balance": "0x10" }}
+                }}"#
+            ))
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(world.balance(address()), U256::ZERO);
+        assert_eq!(world.sload(address(), U256::from(1)), U256::from(1));
+    }
+}
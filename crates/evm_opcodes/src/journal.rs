@@ -0,0 +1,93 @@
+use crate::World;
+use alloy_primitives::U256;
+use std::collections::HashMap;
+
+/// A change of the world state, with what is needed to undo it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JournalEntry {
+    AddressAccessed(U256),
+    StorageAccessed {
+        address: U256,
+        key: U256,
+    },
+    StorageChanged {
+        address: U256,
+        key: U256,
+        previous: U256,
+    },
+    BalanceChanged {
+        address: U256,
+        previous: U256,
+    },
+    TransientStorageChanged {
+        address: U256,
+        key: U256,
+        previous: Option<U256>,
+    },
+    NonceChanged {
+        address: U256,
+        previous: u64,
+    },
+    CodeDeployed(U256),
+    LogEmitted,
+    AccountCreated(U256),
+    SelfDestructed(U256),
+}
+
+impl<CI> World<CI> {
+    /// Position in the journal to go back to if the current call frame fails.
+    pub fn checkpoint(&self) -> usize {
+        self.journal.len()
+    }
+
+    /// Undo all the changes made since the `checkpoint`, in reverse order.
+    pub fn revert_to(&mut self, checkpoint: usize) {
+        let entries = self.journal.split_off(checkpoint);
+        for entry in entries.into_iter().rev() {
+            match entry {
+                JournalEntry::AddressAccessed(address) => {
+                    self.accessed_addresses.remove(&address);
+                }
+                JournalEntry::StorageAccessed { address, key } => {
+                    self.accessed_storage.remove(&(address, key));
+                }
+                JournalEntry::StorageChanged {
+                    address,
+                    key,
+                    previous,
+                } => self.storage.set(address, key, previous),
+                JournalEntry::BalanceChanged { address, previous } => {
+                    self.account_mut(address).balance = previous;
+                }
+                JournalEntry::TransientStorageChanged {
+                    address,
+                    key,
+                    previous,
+                } => {
+                    match previous {
+                        Some(previous) => self.transient_storage.insert((address, key), previous),
+                        None => self.transient_storage.remove(&(address, key)),
+                    };
+                }
+                JournalEntry::NonceChanged { address, previous } => {
+                    self.account_mut(address).nonce = previous;
+                }
+                JournalEntry::CodeDeployed(address) => {
+                    let account = self.account_mut(address);
+                    account.code = None;
+                    account.bytecode.clear();
+                    self.storage.set_immutables(address, HashMap::new());
+                }
+                JournalEntry::LogEmitted => {
+                    self.logs.pop();
+                }
+                JournalEntry::AccountCreated(address) => {
+                    self.created_accounts.remove(&address);
+                }
+                JournalEntry::SelfDestructed(address) => {
+                    self.selfdestructs.remove(&address);
+                }
+            }
+        }
+    }
+}
@@ -3,71 +3,152 @@ use alloy_primitives::{FixedBytes, U256};
 use core::cmp::Ordering;
 use std::collections::HashMap;
 
+pub mod abi;
+mod env;
+mod execution;
 mod i256;
 #[macro_use]
 mod macros;
+pub mod gas;
+mod genesis;
+mod journal;
+pub mod precompiles;
+mod storage;
+mod world;
+
+pub use env::{BlockEnv, TxEnv, BLOCK_HASH_HISTORY};
+pub use execution::{AccountDiff, ExecutionResult, StateDiff};
+pub use gas::Gas;
+pub use journal::JournalEntry;
+pub use storage::{FileStorage, ForkStorage, MemoryStorage, Storage};
+pub use world::{
+    create2_address, create_address, Account, Code, Object, Section, StorageSlot, World,
+    WorldInteractions, CALL_DEPTH_LIMIT,
+};
 
 pub fn from_hex(hex: &str) -> U256 {
     let bytes: FixedBytes<32> = FixedBytes::from_hex(hex).unwrap();
     bytes.into()
 }
 
+/// The memory of a call frame. As in the EVM, it grows by words of 32 bytes and reads as zeros
+/// past its end.
 #[derive(Debug)]
 pub struct Memory {
+    /// At least the active memory, with a whole number of words.
     inner: Vec<u8>,
+    /// Size of the active memory in words, as paid for and returned by `msize`. Every access
+    /// expands it to the highest touched word.
+    words: usize,
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Memory {
     pub fn new() -> Self {
-        let mut memory = Self { inner: Vec::new() };
-        // Initialize the free memory pointer
-        memory.set_byte(0x40 + 0x20 - 1, 0x80);
+        let mut memory = Self {
+            inner: Vec::new(),
+            words: 0,
+        };
+        // Initialize the free memory pointer, without counting it in the active memory
+        memory.store_word(0x40, U256::from(0x80));
+        memory.words = 0;
         memory
     }
 
-    fn get_byte(&self, index: usize) -> u8 {
-        // let index: usize = U256::try_into(index).unwrap();
-        self.inner.get(index).cloned().unwrap_or_default()
+    /// Size of the active memory in bytes.
+    pub fn size(&self) -> usize {
+        self.words * 32
     }
 
-    fn get_buffer(&self, offset: usize, size: usize) -> Vec<u8> {
-        let mut buffer = Vec::with_capacity(size);
-        for i in 0..size {
-            buffer.push(self.get_byte(offset + i));
+    /// Gas to pay to grow the active memory to contain the bytes before `end`.
+    pub fn expansion_cost(&self, end: usize) -> u64 {
+        let words = gas::words(end);
+        let current = self.words as u64;
+        if words > current {
+            gas::memory_cost(words) - gas::memory_cost(current)
+        } else {
+            0
         }
-        buffer
     }
 
-    fn slice_len(&mut self, from: usize, length: usize) -> &[u8] {
-        self.inner.resize(from + length, 0);
-        &self.inner[from..from + length]
+    /// Grow the active memory to contain the bytes before `end`.
+    fn expand(&mut self, end: usize) {
+        self.words = self.words.max(end.div_ceil(32));
+        if self.size() > self.inner.len() {
+            self.inner.resize(self.size(), 0);
+        }
     }
 
-    fn set_byte(&mut self, index: usize, value: u8) {
-        // let index: usize = U256::try_into(index).unwrap();
-        if index >= self.inner.len() {
-            self.inner.resize(index + 1, 0);
+    /// The `size` bytes at `offset`, which must be in the active memory.
+    fn slice(&self, offset: usize, size: usize) -> &[u8] {
+        &self.inner[offset..offset + size]
+    }
+
+    /// Fill `buffer` with the bytes at `offset`.
+    fn read(&self, offset: usize, buffer: &mut [u8]) {
+        let available = self.inner.len().saturating_sub(offset).min(buffer.len());
+        if available > 0 {
+            buffer[..available].copy_from_slice(&self.inner[offset..offset + available]);
         }
-        self.inner[index] = value;
+        buffer[available..].fill(0);
     }
 
-    fn load(&self, address: U256, length: U256) -> Vec<u8> {
-        let address: usize = U256::try_into(address).unwrap();
-        let length: usize = U256::try_into(length).unwrap();
-        let mut result = Vec::new();
+    fn load(&self, address: U256, length: U256) -> YulOutput<Vec<u8>> {
+        let (address, length) = memory_range(address, length)?;
+        let mut buffer = vec![0u8; length];
+        self.read(address, &mut buffer);
+        Ok(buffer)
+    }
 
-        for i in 0..length {
-            result.push(self.get_byte(address + i));
+    fn load_word(&self, offset: usize) -> U256 {
+        let mut word = [0u8; 32];
+        self.read(offset, &mut word);
+        U256::from_be_bytes(word)
+    }
+
+    fn store_word(&mut self, offset: usize, value: U256) {
+        self.expand(offset + 32);
+        self.inner[offset..offset + 32].copy_from_slice(&value.to_be_bytes::<32>());
+    }
+
+    fn store_byte(&mut self, offset: usize, value: u8) {
+        self.expand(offset + 1);
+        self.inner[offset] = value;
+    }
+
+    /// Copy `size` bytes of `source` from `source_offset` to `offset`, reading zeros past the end
+    /// of `source`.
+    fn store_padded(&mut self, offset: usize, size: usize, source: &[u8], source_offset: usize) {
+        if size == 0 {
+            return;
         }
+        self.expand(offset + size);
+        let target = &mut self.inner[offset..offset + size];
+        let available = source.len().saturating_sub(source_offset).min(size);
+        if available > 0 {
+            target[..available].copy_from_slice(&source[source_offset..source_offset + available]);
+        }
+        target[available..].fill(0);
+    }
 
-        result
+    fn store(&mut self, address: U256, value: &[u8]) -> YulOutput<()> {
+        let (address, size) = memory_range(address, U256::from(value.len()))?;
+        self.store_padded(address, size, value, 0);
+        Ok(())
     }
 
-    fn store(&mut self, address: U256, value: &[u8]) {
-        let address: usize = U256::try_into(address).unwrap();
-        for (i, byte) in value.iter().enumerate() {
-            self.set_byte(address + i, *byte);
+    /// Copy `size` bytes from `source` to `target`, where the two ranges may overlap.
+    fn copy(&mut self, target: usize, source: usize, size: usize) {
+        if size == 0 {
+            return;
         }
+        self.expand(target.max(source) + size);
+        self.inner.copy_within(source..source + size, target);
     }
 }
 
@@ -75,84 +156,228 @@ impl Memory {
 pub struct Context<CI> {
     pub contract_interactions: std::marker::PhantomData<CI>,
     pub memory: Memory,
+    /// Maximum size of the memory in bytes. When not set, the memory can grow as much as the gas
+    /// limit of the frame pays for.
+    pub memory_limit: Option<usize>,
     pub immutables: HashMap<U256, U256>,
-    pub storage: HashMap<U256, U256>,
+    pub world: World<CI>,
+    /// Bytes of the running code, followed by the constructor arguments in a `create`.
+    pub code: Vec<u8>,
     pub address: U256,
     pub caller: U256,
     pub callvalue: U256,
-    pub gas: U256,
-    pub timestamp: U256,
+    pub gas: Gas,
     pub calldata: Vec<u8>,
-    pub chain_id: U256,
+    pub block: BlockEnv,
+    pub tx: TxEnv,
+    /// Set when running inside a `staticcall`, where state modifications are forbidden.
+    pub is_static: bool,
+    /// Output of the last message call, as read by `returndatasize` and `returndatacopy`.
+    pub returndata: Vec<u8>,
+    /// Number of message calls above the current frame.
+    pub depth: usize,
+}
+
+/// The opcode used to start a message call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallKind {
+    Call,
+    CallCode,
+    DelegateCall,
+    StaticCall,
+}
+
+/// A message call from the current contract to another one.
+#[derive(Clone, Debug)]
+pub struct Message {
+    pub kind: CallKind,
+    pub gas: U256,
+    /// The `caller` seen by the callee.
+    pub caller: U256,
+    /// The account whose storage and balance are used during the call.
+    pub address: U256,
+    /// The account whose code is executed.
+    pub code_address: U256,
+    /// The `callvalue` seen by the callee.
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub is_static: bool,
+}
+
+impl Message {
+    /// Whether the call moves `value` from the caller to the called address.
+    pub fn transfers_value(&self) -> bool {
+        matches!(self.kind, CallKind::Call | CallKind::CallCode) && !self.value.is_zero()
+    }
 }
 
-pub trait ContractInteractions {
-    fn call(&self, gas: U256, to: U256, payload: &[u8]) -> Vec<u8>;
-    fn get_balance(&self, address: U256) -> U256;
+/// How the address of a new contract is computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CreateScheme {
+    /// From the address and nonce of the creator.
+    Create,
+    /// From the address of the creator, a salt and the hash of the init code (EIP-1014).
+    Create2 { salt: U256 },
+}
 
-    fn log0(&self, payload: &[u8]) {
-        println!("log0: {:?}", payload);
-    }
+/// The creation of a contract by the current one.
+#[derive(Clone, Debug)]
+pub struct CreateMessage {
+    pub scheme: CreateScheme,
+    pub caller: U256,
+    pub value: U256,
+    /// Starts with the name of the translated object to construct, followed by its arguments.
+    pub init_code: Vec<u8>,
+}
 
-    fn log1(&self, payload: &[u8], topic1: U256) {
-        println!("log1: {:?}, {:?}", payload, topic1);
-    }
+/// How a message call ended, with its output data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CallOutcome {
+    Return(Vec<u8>),
+    Revert(Vec<u8>),
+    Halt(Halt),
+}
 
-    fn log2(&self, payload: &[u8], topic1: U256, topic2: U256) {
-        println!("log2: {:?}, {:?}, {:?}", payload, topic1, topic2);
+impl CallOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, CallOutcome::Return(_))
     }
 
-    fn log3(&self, payload: &[u8], topic1: U256, topic2: U256, topic3: U256) {
-        println!(
-            "log3: {:?}, {:?}, {:?}, {:?}",
-            payload, topic1, topic2, topic3
-        );
+    pub fn output(&self) -> &[u8] {
+        match self {
+            CallOutcome::Return(output) | CallOutcome::Revert(output) => output,
+            CallOutcome::Halt(_) => &[],
+        }
     }
+}
 
-    fn log4(&self, payload: &[u8], topic1: U256, topic2: U256, topic3: U256, topic4: U256) {
-        println!(
-            "log4: {:?}, {:?}, {:?}, {:?}, {:?}",
-            payload, topic1, topic2, topic3, topic4
-        );
-    }
+pub trait ContractInteractions {
+    fn call(&mut self, message: Message) -> CallOutcome;
+    /// Create a contract, returning the address of the new contract on success.
+    fn create(&mut self, message: CreateMessage) -> (CallOutcome, Option<U256>);
 }
 
 #[derive(Debug)]
 pub struct DummyContractInteractions;
 
 impl ContractInteractions for Context<DummyContractInteractions> {
-    fn call(&self, _gas: U256, _to: U256, _payload: &[u8]) -> Vec<u8> {
-        vec![]
+    fn call(&mut self, _message: Message) -> CallOutcome {
+        CallOutcome::Return(vec![])
     }
 
-    fn get_balance(&self, _address: U256) -> U256 {
-        U256::ZERO
+    fn create(&mut self, _message: CreateMessage) -> (CallOutcome, Option<U256>) {
+        (CallOutcome::Revert(vec![]), None)
     }
 }
 
+/// Exceptional halts of the EVM. They revert the current frame without any output data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Halt {
+    OutOfGas,
+    InvalidOpcode,
+    /// The call depth limit of 1024 frames was exceeded.
+    StackOverflow,
+    /// A memory offset or size too large to be addressed.
+    MemoryOverflow,
+    /// A memory access past the memory limit of the context.
+    MemoryLimit,
+    /// A `returndatacopy` reading past the end of the return data.
+    ReturnDataOutOfBounds,
+    /// A state modification inside a `staticcall`.
+    StaticStateChange,
+    /// A `create` to an address that already has a contract.
+    CreateCollision,
+    /// A `create` with an init code, or a constructor returning a code, that does not start with
+    /// the name of a registered translated object. Also a `datasize` or `dataoffset` of an object
+    /// that is not part of the running code.
+    UnknownObject,
+    /// An invalid input to a precompile.
+    PrecompileFailure,
+    /// An opcode or a precompile that the translation does not support yet.
+    UnsupportedOpcode(&'static str),
+}
+
 #[derive(Debug)]
 pub enum ReturnOrRevert {
     Return { start: U256, length: U256 },
     Revert { start: U256, length: U256 },
+    Halt(Halt),
 }
 
 pub type YulOutput<A> = Result<A, ReturnOrRevert>;
 
+fn halt<A>(halt: Halt) -> YulOutput<A> {
+    Err(ReturnOrRevert::Halt(halt))
+}
+
+/// Convert an offset and a size in memory to `usize`. As in the EVM, the offset is ignored for
+/// empty ranges.
+fn memory_range(offset: U256, size: U256) -> YulOutput<(usize, usize)> {
+    if size.is_zero() {
+        return Ok((0, 0));
+    }
+    let end = offset
+        .checked_add(size)
+        .ok_or(ReturnOrRevert::Halt(Halt::MemoryOverflow))?;
+    match (
+        usize::try_from(offset),
+        usize::try_from(size),
+        usize::try_from(end),
+    ) {
+        (Ok(offset), Ok(size), Ok(_)) => Ok((offset, size)),
+        _ => halt(Halt::MemoryOverflow),
+    }
+}
+
+impl<CI> Context<CI> {
+    /// Grow the memory to access `size` bytes at `offset`, charging the expansion and checking the
+    /// memory limit. Returns the range as `usize`.
+    fn expand_memory(&mut self, offset: U256, size: U256) -> YulOutput<(usize, usize)> {
+        let (offset, size) = memory_range(offset, size)?;
+        if size > 0 {
+            self.gas.charge(self.memory.expansion_cost(offset + size))?;
+            // Unmetered frames would otherwise allocate any size
+            if offset + size > self.memory_limit() {
+                return halt(Halt::MemoryLimit);
+            }
+            self.memory.expand(offset + size);
+        }
+        Ok((offset, size))
+    }
+
+    pub fn memory_limit(&self) -> usize {
+        self.memory_limit
+            .unwrap_or_else(|| gas::max_memory_size(self.gas.limit))
+    }
+}
+
+/// Fail when modifying the state inside a `staticcall`.
+fn check_not_static<CI>(context: &Context<CI>) -> YulOutput<()> {
+    if context.is_static {
+        return halt(Halt::StaticStateChange);
+    }
+    Ok(())
+}
+
 // Pure opcodes
 
-pub fn add<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn add<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(x.wrapping_add(y))
 }
 
-pub fn sub<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn sub<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(x.wrapping_sub(y))
 }
 
-pub fn mul<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn mul<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
     Ok(x.wrapping_mul(y))
 }
 
-pub fn div<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn div<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
     Ok(if y == U256::ZERO {
         U256::ZERO
     } else {
@@ -160,11 +385,13 @@ pub fn div<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
     })
 }
 
-pub fn sdiv<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn sdiv<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
     Ok(i256::i256_div(x, y))
 }
 
-pub fn mod_<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn mod_<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
     Ok(if y == U256::ZERO {
         U256::ZERO
     } else {
@@ -172,55 +399,68 @@ pub fn mod_<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
     })
 }
 
-pub fn smod<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn smod<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
     Ok(i256::i256_mod(x, y))
 }
 
-pub fn exp<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn exp<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::exp_cost(y))?;
     Ok(x.pow(y))
 }
 
-pub fn not<CI>(x: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn not<CI>(x: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(!x)
 }
 
-pub fn lt<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn lt<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(U256::from(x < y))
 }
 
-pub fn gt<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn gt<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(U256::from(x > y))
 }
 
-pub fn slt<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn slt<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(U256::from(i256::i256_cmp(&x, &y) == Ordering::Less))
 }
 
-pub fn sgt<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn sgt<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(U256::from(i256::i256_cmp(&x, &y) == Ordering::Greater))
 }
 
-pub fn eq<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn eq<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(U256::from(x == y))
 }
 
-pub fn iszero<CI>(x: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn iszero<CI>(x: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(U256::from(x.is_zero()))
 }
 
-pub fn and<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn and<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(x & y)
 }
 
-pub fn or<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn or<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(x | y)
 }
 
-pub fn xor<CI>(x: U256, y: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn xor<CI>(x: U256, y: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     Ok(x ^ y)
 }
 
-pub fn byte<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn byte<CI>(op1: U256, op2: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     let o1 = as_usize_saturated!(op1);
     if o1 < 32 {
         // `31 - o1` because `byte` returns LE, while we want BE
@@ -230,7 +470,8 @@ pub fn byte<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256>
     }
 }
 
-pub fn shl<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn shl<CI>(op1: U256, op2: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     let shift = as_usize_saturated!(op1);
     if shift < 256 {
         Ok(op2 << shift)
@@ -239,7 +480,8 @@ pub fn shl<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256>
     }
 }
 
-pub fn shr<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn shr<CI>(op1: U256, op2: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     let shift = as_usize_saturated!(op1);
     if shift < 256 {
         Ok(op2 >> shift)
@@ -248,7 +490,8 @@ pub fn shr<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256>
     }
 }
 
-pub fn sar<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn sar<CI>(op1: U256, op2: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
     let shift = as_usize_saturated!(op1);
     if shift < 256 {
         Ok(op2.arithmetic_shr(shift))
@@ -259,15 +502,18 @@ pub fn sar<CI>(op1: U256, op2: U256, _context: &Context<CI>) -> YulOutput<U256>
     }
 }
 
-pub fn addmod<CI>(op1: U256, op2: U256, op3: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn addmod<CI>(op1: U256, op2: U256, op3: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::MID)?;
     Ok(op1.add_mod(op2, op3))
 }
 
-pub fn mulmod<CI>(op1: U256, op2: U256, op3: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn mulmod<CI>(op1: U256, op2: U256, op3: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::MID)?;
     Ok(op1.mul_mod(op2, op3))
 }
 
-pub fn signextend<CI>(ext: U256, x: U256, _context: &Context<CI>) -> YulOutput<U256> {
+pub fn signextend<CI>(ext: U256, x: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
     // For 31 we also don't need to do anything.
     if ext < U256::from(31) {
         let ext = ext.as_limbs()[0];
@@ -284,244 +530,480 @@ pub fn signextend<CI>(ext: U256, x: U256, _context: &Context<CI>) -> YulOutput<U
     }
 }
 
-pub fn keccak256<CI>(p: U256, n: U256, _context: &mut Context<CI>) -> YulOutput<U256> {
-    let p: usize = U256::try_into(p).unwrap();
-    let n: usize = U256::try_into(n).unwrap();
-    let slice = _context.memory.slice_len(p, n);
-    Ok(alloy_primitives::keccak256(slice).into())
+pub fn keccak256<CI>(p: U256, n: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::keccak256_cost(n))?;
+    let (p, n) = context.expand_memory(p, n)?;
+    Ok(alloy_primitives::keccak256(context.memory.slice(p, n)).into())
 }
 
-pub fn pop<CI>(_x: U256, _context: &Context<CI>) -> YulOutput<()> {
+pub fn pop<CI>(_x: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    context.gas.charge(gas::BASE)?;
     Ok(())
 }
 
 // Memory opcodes
 
-pub fn mload<CI>(address: U256, context: &Context<CI>) -> YulOutput<U256> {
-    let bytes: Vec<u8> = context.memory.load(address, U256::from(32));
-    let bytes: [u8; 32] = bytes.try_into().unwrap();
-    let bytes: FixedBytes<32> = bytes.into();
-    Ok(U256::try_from(bytes).unwrap())
+pub fn mload<CI>(address: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
+    let (address, _) = context.expand_memory(address, U256::from(32))?;
+    Ok(context.memory.load_word(address))
 }
 
 pub fn mstore<CI>(address: U256, value: U256, context: &mut Context<CI>) -> YulOutput<()> {
-    let bytes: [u8; 32] = value.to_be_bytes::<32>();
-    context.memory.store(address, &bytes);
+    context.gas.charge(gas::VERYLOW)?;
+    let (address, _) = context.expand_memory(address, U256::from(32))?;
+    context.memory.store_word(address, value);
     Ok(())
 }
 
 pub fn mstore8<CI>(address: U256, value: U256, context: &mut Context<CI>) -> YulOutput<()> {
-    context.memory.store(address, &[value.byte(0)]);
+    context.gas.charge(gas::VERYLOW)?;
+    let (address, _) = context.expand_memory(address, U256::from(1))?;
+    context.memory.store_byte(address, value.byte(0));
     Ok(())
 }
 
-pub fn sload<CI>(p: U256, context: &Context<CI>) -> YulOutput<U256> {
-    Ok(context.storage.get(&p).cloned().unwrap_or(U256::ZERO))
+pub fn sload<CI>(p: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    let is_warm = context.world.access_storage(context.address, p);
+    context.gas.charge(gas::sload_cost(is_warm))?;
+    Ok(context.world.sload(context.address, p))
 }
 
 pub fn sstore<CI>(p: U256, v: U256, context: &mut Context<CI>) -> YulOutput<()> {
-    context.storage.insert(p, v);
+    check_not_static(context)?;
+    // A `sstore` always fails with less than the call stipend left (EIP-2200)
+    if context.gas.metered && context.gas.remaining <= gas::CALL_STIPEND {
+        return halt(Halt::OutOfGas);
+    }
+    let is_warm = context.world.access_storage(context.address, p);
+    let StorageSlot { original, current } = context.world.storage_slot(context.address, p);
+    context
+        .gas
+        .charge(gas::sstore_cost(is_warm, original, current, v))?;
+    if context.gas.metered {
+        context.gas.refunded += gas::sstore_refund(original, current, v);
+    }
+    context.world.sstore(context.address, p, v);
+    Ok(())
+}
+
+pub fn tload<CI>(p: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::WARM_ACCESS)?;
+    Ok(context.world.tload(context.address, p))
+}
+
+pub fn tstore<CI>(p: U256, v: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    check_not_static(context)?;
+    context.gas.charge(gas::WARM_ACCESS)?;
+    context.world.tstore(context.address, p, v);
     Ok(())
 }
 
-pub fn gas<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    Ok(_context.gas)
+pub fn gas<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(U256::from(context.gas.remaining))
 }
 
-pub fn address<CI>(context: &Context<CI>) -> YulOutput<U256> {
+pub fn address<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
     Ok(context.address)
 }
 
-pub fn balance<CI>(address: U256, context: &Context<CI>) -> YulOutput<U256>
-where
-    Context<CI>: ContractInteractions,
-{
-    Ok(context.get_balance(address))
+pub fn balance<CI>(address: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    let is_warm = context.world.access_address(address);
+    context.gas.charge(gas::account_access_cost(is_warm))?;
+    Ok(context.world.balance(address))
 }
 
-pub fn selfbalance<CI>(context: &Context<CI>) -> YulOutput<U256>
-where
-    Context<CI>: ContractInteractions,
-{
-    balance(address(context)?, context)
+pub fn selfbalance<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::LOW)?;
+    Ok(context.world.balance(context.address))
 }
 
-pub fn caller<CI>(context: &Context<CI>) -> YulOutput<U256> {
+pub fn caller<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
     Ok(context.caller)
 }
 
-pub fn callvalue<CI>(context: &Context<CI>) -> YulOutput<U256> {
+pub fn callvalue<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
     Ok(context.callvalue)
 }
 
-pub fn calldataload<CI>(p: U256, context: &Context<CI>) -> YulOutput<U256> {
-    let p: usize = U256::try_into(p).unwrap();
-    Ok(U256::from(context.calldata[p]))
+pub fn calldataload<CI>(p: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
+    // Bytes past the end of the calldata are read as zeros
+    let mut word = [0u8; 32];
+    let p = as_usize_saturated!(p);
+    if p < context.calldata.len() {
+        let length = (context.calldata.len() - p).min(32);
+        word[..length].copy_from_slice(&context.calldata[p..p + length]);
+    }
+    Ok(U256::from_be_bytes(word))
 }
 
-pub fn calldatasize<CI>(context: &Context<CI>) -> YulOutput<U256> {
+pub fn calldatasize<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
     Ok(U256::from(context.calldata.len()))
 }
 
 pub fn calldatacopy<CI>(t: U256, f: U256, s: U256, context: &mut Context<CI>) -> YulOutput<()> {
-    let f: usize = U256::try_into(f).unwrap();
-    let s: usize = U256::try_into(s).unwrap();
-    let t: usize = U256::try_into(t).unwrap();
-    for i in 0..s {
-        if f + i < context.calldata.len() {
-            context.memory.set_byte(t + i, context.calldata[f + i]);
-        } else {
-            context.memory.set_byte(t + i, 0);
-        }
-    }
+    context.gas.charge(gas::copy_cost(s))?;
+    let (t, s) = context.expand_memory(t, s)?;
+    let f = as_usize_saturated!(f);
+    context.memory.store_padded(t, s, &context.calldata, f);
     Ok(())
 }
 
-pub fn codesize<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn codesize<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(U256::from(context.code.len()))
 }
 
-pub fn codecopy<CI>(_t: U256, _f: U256, _s: U256, _context: &Context<CI>) -> YulOutput<()> {
-    unimplemented!()
+pub fn codecopy<CI>(t: U256, f: U256, s: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    context.gas.charge(gas::copy_cost(s))?;
+    let (t, s) = context.expand_memory(t, s)?;
+    let f = as_usize_saturated!(f);
+    context.memory.store_padded(t, s, &context.code, f);
+    Ok(())
 }
 
-pub fn extcodesize<CI>(_a: U256, _context: &Context<CI>) -> YulOutput<U256> {
-    Ok(U256::from(1))
+pub fn extcodesize<CI>(a: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    let is_warm = context.world.access_address(a);
+    context.gas.charge(gas::account_access_cost(is_warm))?;
+    Ok(U256::from(context.world.code_size(a)))
 }
 
 pub fn extcodecopy<CI>(
-    _a: U256,
-    _t: U256,
+    a: U256,
+    t: U256,
     _f: U256,
-    _s: U256,
-    _context: &Context<CI>,
+    s: U256,
+    context: &mut Context<CI>,
 ) -> YulOutput<()> {
-    unimplemented!()
+    let is_warm = context.world.access_address(a);
+    context.gas.charge(gas::account_access_cost(is_warm))?;
+    context.gas.charge(gas::copy_cost(s))?;
+    context.expand_memory(t, s)?;
+    halt(Halt::UnsupportedOpcode("extcodecopy"))
 }
 
-pub fn returndatasize<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn returndatasize<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(U256::from(context.returndata.len()))
 }
 
-pub fn returndatacopy<CI>(_t: U256, _f: U256, _s: U256, _context: &Context<CI>) -> YulOutput<()> {
-    unimplemented!()
+pub fn returndatacopy<CI>(t: U256, f: U256, s: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    context.gas.charge(gas::copy_cost(s))?;
+    context.expand_memory(t, s)?;
+    // Unlike the other copy opcodes, reading past the end of the return data is an error
+    match f.checked_add(s) {
+        Some(end) if end <= U256::from(context.returndata.len()) => {}
+        _ => return halt(Halt::ReturnDataOutOfBounds),
+    }
+    // Both fit in `usize` as they are bounded by the length of the return data
+    let f = as_usize_saturated!(f);
+    let s = as_usize_saturated!(s);
+    context.memory.store(t, &context.returndata[f..f + s])
 }
 
 pub fn mcopy<CI>(
     dest_offset: U256,
     offset: U256,
     size: U256,
-    _context: &mut Context<CI>,
+    context: &mut Context<CI>,
 ) -> YulOutput<()> {
-    let dest_offset: usize = U256::try_into(dest_offset).unwrap();
-    let offset: usize = U256::try_into(offset).unwrap();
-    let size: usize = U256::try_into(size).unwrap();
-    let buffer = _context.memory.get_buffer(offset, size);
-
-    for i in 0..size {
-        _context.memory.set_byte(dest_offset + i, buffer[i]);
-    }
-
+    context.gas.charge(gas::copy_cost(size))?;
+    let (offset, _) = context.expand_memory(offset, size)?;
+    let (dest_offset, size) = context.expand_memory(dest_offset, size)?;
+    context.memory.copy(dest_offset, offset, size);
     Ok(())
 }
 
-pub fn extcodehash<CI>(_a: U256, _context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn extcodehash<CI>(a: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    let is_warm = context.world.access_address(a);
+    context.gas.charge(gas::account_access_cost(is_warm))?;
+    halt(Halt::UnsupportedOpcode("extcodehash"))
 }
 
-pub fn call<CI>(
-    _g: U256,
-    _a: U256,
-    _v: U256,
-    _in_: U256,
-    _insize: U256,
-    _out: U256,
-    _outsize: U256,
-    _context: &Context<CI>,
-) -> YulOutput<U256> {
-    unimplemented!()
+/// Charge the access to the called account and the memory of the input and output.
+fn charge_call<CI>(
+    address: U256,
+    in_: U256,
+    insize: U256,
+    out: U256,
+    outsize: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<()> {
+    let is_warm = context.world.access_address(address);
+    context.gas.charge(gas::account_access_cost(is_warm))?;
+    context.expand_memory(in_, insize)?;
+    context.expand_memory(out, outsize)?;
+    Ok(())
 }
 
+/// Run a message call and copy its output to `memory[out..out + outsize]`, returning the
+/// success flag.
+fn message_call<CI>(
+    message: Message,
+    out: U256,
+    outsize: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    let outcome = if precompiles::is_precompile(message.code_address) {
+        context.call_precompile(message)
+    } else {
+        context.call(message)
+    };
+    let success = outcome.is_success();
+    let output = match outcome {
+        CallOutcome::Return(output) | CallOutcome::Revert(output) => output,
+        CallOutcome::Halt(_) => vec![],
+    };
+    let length = output.len().min(as_usize_saturated!(outsize));
+    context.memory.store(out, &output[..length])?;
+    context.returndata = output;
+    Ok(U256::from(success))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn call<CI>(
+    g: U256,
+    a: U256,
+    v: U256,
+    in_: U256,
+    insize: U256,
+    out: U256,
+    outsize: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    // Transferring value is a state modification
+    charge_call(a, in_, insize, out, outsize, context)?;
+    if !v.is_zero() {
+        check_not_static(context)?;
+        context.gas.charge(gas::CALL_VALUE)?;
+        if context.world.is_empty(a) {
+            context.gas.charge(gas::NEW_ACCOUNT)?;
+        }
+    }
+    let message = Message {
+        kind: CallKind::Call,
+        gas: g,
+        caller: context.address,
+        address: a,
+        code_address: a,
+        value: v,
+        input: context.memory.load(in_, insize)?,
+        is_static: context.is_static,
+    };
+    message_call(message, out, outsize, context)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn callcode<CI>(
-    _g: U256,
-    _a: U256,
-    _v: U256,
-    _in_: U256,
-    _insize: U256,
-    _out: U256,
-    _outsize: U256,
-    _context: &Context<CI>,
-) -> YulOutput<U256> {
-    unimplemented!()
+    g: U256,
+    a: U256,
+    v: U256,
+    in_: U256,
+    insize: U256,
+    out: U256,
+    outsize: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    charge_call(a, in_, insize, out, outsize, context)?;
+    if !v.is_zero() {
+        context.gas.charge(gas::CALL_VALUE)?;
+    }
+    let message = Message {
+        kind: CallKind::CallCode,
+        gas: g,
+        caller: context.address,
+        address: context.address,
+        code_address: a,
+        value: v,
+        input: context.memory.load(in_, insize)?,
+        is_static: context.is_static,
+    };
+    message_call(message, out, outsize, context)
 }
 
 pub fn delegatecall<CI>(
-    _g: U256,
-    _a: U256,
-    _in_: U256,
-    _insize: U256,
-    _out: U256,
-    _outsize: U256,
-    _context: &Context<CI>,
-) -> YulOutput<U256> {
-    unimplemented!()
+    g: U256,
+    a: U256,
+    in_: U256,
+    insize: U256,
+    out: U256,
+    outsize: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    charge_call(a, in_, insize, out, outsize, context)?;
+    // The callee runs with our caller and value, without transferring anything
+    let message = Message {
+        kind: CallKind::DelegateCall,
+        gas: g,
+        caller: context.caller,
+        address: context.address,
+        code_address: a,
+        value: context.callvalue,
+        input: context.memory.load(in_, insize)?,
+        is_static: context.is_static,
+    };
+    message_call(message, out, outsize, context)
 }
 
 pub fn staticcall<CI>(
-    _gas: U256,
-    _address: U256,
-    _args_offset: U256,
-    _args_size: U256,
-    _ret_offset: U256,
-    _ret_size: U256,
-    _context: &Context<CI>,
-) -> YulOutput<U256> {
-    // Pre-compiles for the ZK verifier: 2, 5, 6, 7, 8
-    Ok(U256::ONE)
-}
-
-pub fn return_<CI>(offset: U256, size: U256, _context: &mut Context<CI>) -> YulOutput<()> {
+    gas: U256,
+    address: U256,
+    args_offset: U256,
+    args_size: U256,
+    ret_offset: U256,
+    ret_size: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    charge_call(
+        address,
+        args_offset,
+        args_size,
+        ret_offset,
+        ret_size,
+        context,
+    )?;
+    let message = Message {
+        kind: CallKind::StaticCall,
+        gas,
+        caller: context.address,
+        address,
+        code_address: address,
+        value: U256::ZERO,
+        input: context.memory.load(args_offset, args_size)?,
+        is_static: true,
+    };
+    message_call(message, ret_offset, ret_size, context)
+}
+
+/// Run a contract creation, returning the address of the new contract or zero on failure.
+fn create_contract<CI>(
+    scheme: CreateScheme,
+    value: U256,
+    offset: U256,
+    size: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    check_not_static(context)?;
+    context.expand_memory(offset, size)?;
+    let message = CreateMessage {
+        scheme,
+        caller: context.address,
+        value,
+        init_code: context.memory.load(offset, size)?,
+    };
+    let (outcome, address) = context.create(message);
+    // Only reverted creations have return data
+    context.returndata = match outcome {
+        CallOutcome::Revert(output) => output,
+        CallOutcome::Return(_) | CallOutcome::Halt(_) => vec![],
+    };
+    Ok(address.unwrap_or_default())
+}
+
+pub fn create<CI>(v: U256, p: U256, n: U256, context: &mut Context<CI>) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    context.gas.charge(gas::create_cost(n))?;
+    create_contract(CreateScheme::Create, v, p, n, context)
+}
+
+pub fn create2<CI>(v: U256, p: U256, n: U256, s: U256, context: &mut Context<CI>) -> YulOutput<U256>
+where
+    Context<CI>: ContractInteractions,
+{
+    context.gas.charge(gas::create2_cost(n))?;
+    create_contract(CreateScheme::Create2 { salt: s }, v, p, n, context)
+}
+
+pub fn return_<CI>(offset: U256, size: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    context.expand_memory(offset, size)?;
     Err(ReturnOrRevert::Return {
         start: offset,
         length: size,
     })
 }
 
-pub fn revert<CI>(offset: U256, size: U256, _context: &mut Context<CI>) -> YulOutput<()> {
+pub fn revert<CI>(offset: U256, size: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    context.expand_memory(offset, size)?;
     Err(ReturnOrRevert::Revert {
         start: offset,
         length: size,
     })
 }
 
-pub fn selfdestruct<CI>(_a: U256, _context: &mut Context<CI>) -> YulOutput<()> {
-    unimplemented!()
+pub fn selfdestruct<CI>(a: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    check_not_static(context)?;
+    let is_warm = context.world.access_address(a);
+    let creates_account =
+        !context.world.balance(context.address).is_zero() && context.world.is_empty(a);
+    context
+        .gas
+        .charge(gas::selfdestruct_cost(is_warm, creates_account))?;
+    context.world.selfdestruct(context.address, a);
+    // Stop the execution without output
+    Err(ReturnOrRevert::Return {
+        start: U256::ZERO,
+        length: U256::ZERO,
+    })
 }
 
-pub fn invalid() -> YulOutput<()> {
-    unimplemented!()
+pub fn invalid<CI>(_context: &mut Context<CI>) -> YulOutput<()> {
+    halt(Halt::InvalidOpcode)
 }
 
-pub fn log0<CI>(offset: U256, size: U256, context: &Context<CI>) -> YulOutput<()>
-where
-    Context<CI>: ContractInteractions,
-{
-    let offset: usize = U256::try_into(offset).unwrap();
-    let size: usize = U256::try_into(size).unwrap();
-    let buffer = context.memory.get_buffer(offset, size);
-    context.log0(&buffer);
+/// Record a log of the current contract in the world, to be dropped if the frame reverts.
+fn log<CI>(
+    offset: U256,
+    size: U256,
+    topics: Vec<U256>,
+    context: &mut Context<CI>,
+) -> YulOutput<()> {
+    check_not_static(context)?;
+    context
+        .gas
+        .charge(gas::log_cost(topics.len() as u64, size))?;
+    context.expand_memory(offset, size)?;
+    let buffer = context.memory.load(offset, size)?;
+    context.world.log(context.address, topics, buffer);
     Ok(())
 }
 
-pub fn log1<CI>(offset: U256, size: U256, topic1: U256, context: &Context<CI>) -> YulOutput<()>
-where
-    Context<CI>: ContractInteractions,
-{
-    let offset: usize = U256::try_into(offset).unwrap();
-    let size: usize = U256::try_into(size).unwrap();
-    let buffer = context.memory.get_buffer(offset, size);
-    context.log1(&buffer, topic1);
-    Ok(())
+pub fn log0<CI>(offset: U256, size: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    log(offset, size, vec![], context)
+}
+
+pub fn log1<CI>(
+    offset: U256,
+    size: U256,
+    topic1: U256,
+    context: &mut Context<CI>,
+) -> YulOutput<()> {
+    log(offset, size, vec![topic1], context)
 }
 
 pub fn log2<CI>(
@@ -529,16 +1011,9 @@ pub fn log2<CI>(
     size: U256,
     topic1: U256,
     topic2: U256,
-    context: &Context<CI>,
-) -> YulOutput<()>
-where
-    Context<CI>: ContractInteractions,
-{
-    let offset: usize = U256::try_into(offset).unwrap();
-    let size: usize = U256::try_into(size).unwrap();
-    let buffer = context.memory.get_buffer(offset, size);
-    context.log2(&buffer, topic1, topic2);
-    Ok(())
+    context: &mut Context<CI>,
+) -> YulOutput<()> {
+    log(offset, size, vec![topic1, topic2], context)
 }
 
 pub fn log3<CI>(
@@ -547,16 +1022,9 @@ pub fn log3<CI>(
     topic1: U256,
     topic2: U256,
     topic3: U256,
-    context: &Context<CI>,
-) -> YulOutput<()>
-where
-    Context<CI>: ContractInteractions,
-{
-    let offset: usize = U256::try_into(offset).unwrap();
-    let size: usize = U256::try_into(size).unwrap();
-    let buffer = context.memory.get_buffer(offset, size);
-    context.log3(&buffer, topic1, topic2, topic3);
-    Ok(())
+    context: &mut Context<CI>,
+) -> YulOutput<()> {
+    log(offset, size, vec![topic1, topic2, topic3], context)
 }
 
 pub fn log4<CI>(
@@ -566,86 +1034,111 @@ pub fn log4<CI>(
     topic2: U256,
     topic3: U256,
     topic4: U256,
-    context: &Context<CI>,
-) -> YulOutput<()>
-where
-    Context<CI>: ContractInteractions,
-{
-    let offset: usize = U256::try_into(offset).unwrap();
-    let size: usize = U256::try_into(size).unwrap();
-    let buffer = context.memory.get_buffer(offset, size);
-    context.log4(&buffer, topic1, topic2, topic3, topic4);
-    Ok(())
+    context: &mut Context<CI>,
+) -> YulOutput<()> {
+    log(offset, size, vec![topic1, topic2, topic3, topic4], context)
 }
 
-pub fn chainid<CI>(context: &Context<CI>) -> YulOutput<U256> {
-    Ok(context.chain_id)
+pub fn chainid<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.chain_id)
 }
 
-pub fn basefee<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn basefee<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.basefee)
 }
 
-pub fn blobbasefee<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn blobbasefee<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.blob_basefee)
 }
 
-pub fn origin<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn origin<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.tx.origin)
 }
 
-pub fn gasprice<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn gasprice<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.tx.gas_price)
 }
 
-pub fn blockhash<CI>(_b: U256, _context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn blockhash<CI>(b: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BLOCKHASH)?;
+    Ok(context.block.block_hash(b))
 }
 
-pub fn blobhash<CI>(_i: U256, _context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn blobhash<CI>(i: U256, context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::VERYLOW)?;
+    let hash = usize::try_from(i)
+        .ok()
+        .and_then(|i| context.tx.blob_hashes.get(i));
+    Ok(hash.cloned().unwrap_or_default())
 }
 
-pub fn coinbase<CI>() -> YulOutput<U256> {
-    unimplemented!()
+pub fn coinbase<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.coinbase)
 }
 
-pub fn timestamp<CI>(context: &Context<CI>) -> YulOutput<U256> {
-    Ok(context.timestamp)
+pub fn timestamp<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.timestamp)
 }
 
-pub fn number<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn number<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.number)
 }
 
-pub fn difficulty<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn difficulty<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.prevrandao)
 }
 
-pub fn prevrandao<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn prevrandao<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.prevrandao)
 }
 
-pub fn gaslimit<CI>(_context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn gaslimit<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(context.block.gas_limit)
 }
 
 // Special opcodes
 
+pub fn msize<CI>(context: &mut Context<CI>) -> YulOutput<U256> {
+    context.gas.charge(gas::BASE)?;
+    Ok(U256::from(context.memory.size()))
+}
+
 pub fn memoryguard<CI>(size: U256, _context: &mut Context<CI>) -> YulOutput<U256> {
     Ok(size)
 }
 
-pub fn datasize<CI>(_x: U256, _context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+/// Offset and size of the object or data section `name` in the running code.
+fn data_range<CI>(name: U256, context: &Context<CI>) -> YulOutput<(usize, usize)> {
+    context
+        .world
+        .object(&context.code)
+        .and_then(|object| object.layout.get(&name).cloned())
+        .ok_or(ReturnOrRevert::Halt(Halt::UnknownObject))
+}
+
+pub fn datasize<CI>(x: U256, context: &Context<CI>) -> YulOutput<U256> {
+    let (_, size) = data_range(x, context)?;
+    Ok(U256::from(size))
 }
 
-pub fn dataoffset<CI>(_x: U256, _context: &Context<CI>) -> YulOutput<U256> {
-    unimplemented!()
+pub fn dataoffset<CI>(x: U256, context: &Context<CI>) -> YulOutput<U256> {
+    let (offset, _) = data_range(x, context)?;
+    Ok(U256::from(offset))
 }
 
-pub fn datacopy<CI>(t: U256, f: U256, s: U256, _context: &Context<CI>) -> YulOutput<()> {
-    codecopy(t, f, s, _context)
+pub fn datacopy<CI>(t: U256, f: U256, s: U256, context: &mut Context<CI>) -> YulOutput<()> {
+    codecopy(t, f, s, context)
 }
 
 pub fn setimmutable<CI>(
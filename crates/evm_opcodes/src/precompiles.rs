@@ -0,0 +1,484 @@
+//! Precompiled contracts at the addresses 0x01 to 0x0a.
+//!
+//! They run with the gas given to the call, and fail by consuming all of it.
+
+mod blake2;
+
+use crate::gas::words;
+use crate::{halt, Gas, Halt, YulOutput};
+use alloy_primitives::{hex, keccak256, U256};
+use c_kzg::{Bytes32, Bytes48};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use num_bigint::BigUint;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use substrate_bn::{AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+
+/// Run with the input of the call, charging the gas and returning the output. The last argument
+/// is the memory limit of the call frame, by which the buffers sized by the input can exceed it.
+pub type Precompile = fn(&[u8], &mut Gas, usize) -> YulOutput<Vec<u8>>;
+
+pub fn is_precompile(address: U256) -> bool {
+    get(address).is_some()
+}
+
+pub fn get(address: U256) -> Option<Precompile> {
+    let precompile: Precompile = match u64::try_from(address).ok()? {
+        0x01 => ecrecover,
+        0x02 => sha256,
+        0x03 => ripemd160,
+        0x04 => identity,
+        0x05 => modexp,
+        0x06 => ec_add,
+        0x07 => ec_mul,
+        0x08 => ec_pairing,
+        0x09 => blake2f,
+        0x0a => point_evaluation,
+        _ => return None,
+    };
+    Some(precompile)
+}
+
+fn failure<A>() -> YulOutput<A> {
+    halt(Halt::PrecompileFailure)
+}
+
+/// The first `N` bytes of the input, padded with zeros.
+fn padded<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let length = input.len().min(N);
+    bytes[..length].copy_from_slice(&input[..length]);
+    bytes
+}
+
+/// Read `length` bytes at `offset`, padded with zeros.
+fn read(input: &[u8], offset: usize, length: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; length];
+    if offset < input.len() {
+        let available = (input.len() - offset).min(length);
+        bytes[..available].copy_from_slice(&input[offset..offset + available]);
+    }
+    bytes
+}
+
+fn word(input: &[u8], index: usize) -> U256 {
+    U256::from_be_slice(&input[32 * index..32 * (index + 1)])
+}
+
+fn ecrecover(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(3000)?;
+    let input = padded::<128>(input);
+    let v = word(&input, 1);
+    // Invalid signatures succeed without output
+    if v != U256::from(27) && v != U256::from(28) {
+        return Ok(vec![]);
+    }
+    let Ok(mut signature) = Signature::from_slice(&input[64..]) else {
+        return Ok(vec![]);
+    };
+    let mut recovery_id = v.byte(0) - 27;
+    // Unlike transactions, the precompile accepts the signatures with a high `s`
+    if let Some(normalized) = signature.normalize_s() {
+        signature = normalized;
+        recovery_id ^= 1;
+    }
+    let recovery_id = RecoveryId::from_byte(recovery_id).unwrap();
+    let Ok(key) = VerifyingKey::recover_from_prehash(&input[..32], &signature, recovery_id) else {
+        return Ok(vec![]);
+    };
+    let hash = keccak256(&key.to_encoded_point(false).as_bytes()[1..]);
+    let mut output = vec![0u8; 32];
+    output[12..].copy_from_slice(&hash[12..]);
+    Ok(output)
+}
+
+fn sha256(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(60 + 12 * words(input.len()))?;
+    Ok(Sha256::digest(input).to_vec())
+}
+
+fn ripemd160(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(600 + 120 * words(input.len()))?;
+    let mut output = vec![0u8; 32];
+    output[12..].copy_from_slice(&Ripemd160::digest(input));
+    Ok(output)
+}
+
+fn identity(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(15 + 3 * words(input.len()))?;
+    Ok(input.to_vec())
+}
+
+/// Cost of a `modexp`, following EIP-2565.
+fn modexp_cost(
+    base_length: usize,
+    exponent_length: usize,
+    modulus_length: usize,
+    exponent_head: U256,
+) -> u64 {
+    let words = base_length.max(modulus_length).div_ceil(8) as u64;
+    let complexity = words.saturating_mul(words);
+    let head_bits = exponent_head.bit_len().saturating_sub(1) as u64;
+    let iterations = if exponent_length <= 32 {
+        head_bits
+    } else {
+        8u64.saturating_mul(exponent_length as u64 - 32)
+            .saturating_add(head_bits)
+    };
+    (complexity.saturating_mul(iterations.max(1)) / 3).max(200)
+}
+
+fn modexp(input: &[u8], gas: &mut Gas, memory_limit: usize) -> YulOutput<Vec<u8>> {
+    let header = padded::<96>(input);
+    let lengths = (0..3)
+        .map(|index| usize::try_from(word(&header, index)))
+        .collect::<Result<Vec<_>, _>>();
+    // Such lengths could never be paid for
+    let Ok(&[base_length, exponent_length, modulus_length]) = lengths.as_deref() else {
+        return halt(Halt::OutOfGas);
+    };
+    let data = input.get(96..).unwrap_or_default();
+    let exponent_head = U256::from_be_slice(&read(data, base_length, exponent_length.min(32)));
+    gas.charge(modexp_cost(
+        base_length,
+        exponent_length,
+        modulus_length,
+        exponent_head,
+    ))?;
+    // Empty, whatever the exponent, which is then not read
+    if base_length == 0 && modulus_length == 0 {
+        return Ok(vec![]);
+    }
+    // When metered, the gas could not pay for such buffers
+    if !gas.metered
+        && base_length.max(exponent_length).max(modulus_length)
+            > input.len().saturating_add(memory_limit)
+    {
+        return halt(Halt::MemoryLimit);
+    }
+
+    let base = BigUint::from_bytes_be(&read(data, 0, base_length));
+    let exponent = BigUint::from_bytes_be(&read(data, base_length, exponent_length));
+    let modulus = BigUint::from_bytes_be(&read(
+        data,
+        base_length.saturating_add(exponent_length),
+        modulus_length,
+    ));
+    let result = if modulus.bits() == 0 {
+        vec![]
+    } else {
+        base.modpow(&exponent, &modulus).to_bytes_be()
+    };
+    // Left-pad the result to the length of the modulus
+    let mut output = vec![0u8; modulus_length];
+    output[modulus_length - result.len()..].copy_from_slice(&result);
+    Ok(output)
+}
+
+fn read_fq(input: &[u8], index: usize) -> YulOutput<Fq> {
+    Fq::from_slice(&input[32 * index..32 * (index + 1)]).map_or_else(|_| failure(), Ok)
+}
+
+/// Read a point of G1 as two coordinates, with `(0, 0)` for the point at infinity.
+fn read_g1(input: &[u8]) -> YulOutput<G1> {
+    let (x, y) = (read_fq(input, 0)?, read_fq(input, 1)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1::zero());
+    }
+    AffineG1::new(x, y).map_or_else(|_| failure(), |point| Ok(point.into()))
+}
+
+/// Read a point of G2, with the imaginary part of each coordinate first.
+fn read_g2(input: &[u8]) -> YulOutput<G2> {
+    let x = Fq2::new(read_fq(input, 1)?, read_fq(input, 0)?);
+    let y = Fq2::new(read_fq(input, 3)?, read_fq(input, 2)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2::zero());
+    }
+    // Also checks that the point is in the subgroup of order r
+    AffineG2::new(x, y).map_or_else(|_| failure(), |point| Ok(point.into()))
+}
+
+fn write_g1(point: G1) -> Vec<u8> {
+    let mut output = vec![0u8; 64];
+    if let Some(point) = AffineG1::from_jacobian(point) {
+        point.x().to_big_endian(&mut output[..32]).unwrap();
+        point.y().to_big_endian(&mut output[32..]).unwrap();
+    }
+    output
+}
+
+fn ec_add(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(150)?;
+    let input = padded::<128>(input);
+    let (p, q) = (read_g1(&input[..64])?, read_g1(&input[64..])?);
+    Ok(write_g1(p + q))
+}
+
+fn ec_mul(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(6000)?;
+    let input = padded::<96>(input);
+    let p = read_g1(&input[..64])?;
+    // The scalar is reduced modulo the order of the group
+    let scalar = Fr::from_slice(&input[64..96]).unwrap();
+    Ok(write_g1(p * scalar))
+}
+
+fn ec_pairing(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    if !input.len().is_multiple_of(192) {
+        return failure();
+    }
+    let pairs = input.len() / 192;
+    gas.charge(45000u64.saturating_add(34000u64.saturating_mul(pairs as u64)))?;
+    let pairs = input
+        .chunks(192)
+        .map(|pair| Ok((read_g1(&pair[..64])?, read_g2(&pair[64..])?)))
+        .collect::<YulOutput<Vec<_>>>()?;
+    let is_one = substrate_bn::pairing_batch(&pairs) == Gt::one();
+    Ok(U256::from(is_one).to_be_bytes_vec())
+}
+
+fn blake2f(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    if input.len() != 213 {
+        return failure();
+    }
+    let rounds = u32::from_be_bytes(input[..4].try_into().unwrap());
+    // When unmetered, still run at most the rounds that the gas limit pays for
+    if !gas.metered && rounds as u64 > gas.limit {
+        return halt(Halt::OutOfGas);
+    }
+    gas.charge(rounds as u64)?;
+    let read_u64 =
+        |offset: usize| u64::from_le_bytes(input[offset..offset + 8].try_into().unwrap());
+    let mut h: [u64; 8] = std::array::from_fn(|i| read_u64(4 + 8 * i));
+    let m: [u64; 16] = std::array::from_fn(|i| read_u64(68 + 8 * i));
+    let t = [read_u64(196), read_u64(204)];
+    let is_final = match input[212] {
+        0 => false,
+        1 => true,
+        _ => return failure(),
+    };
+    blake2::compress(rounds, &mut h, &m, t, is_final);
+    Ok(h.iter().flat_map(|word| word.to_le_bytes()).collect())
+}
+
+/// Modulus of the scalar field of BLS12-381, returned with the number of field elements per
+/// blob by the point evaluation.
+const BLS_MODULUS: U256 = U256::from_be_bytes(hex!(
+    "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+));
+const FIELD_ELEMENTS_PER_BLOB: U256 = U256::from_limbs([4096, 0, 0, 0]);
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// Check that the polynomial committed to by a blob takes the value `y` at `z` (EIP-4844),
+/// with the trusted setup of Ethereum.
+fn point_evaluation(input: &[u8], gas: &mut Gas, _memory_limit: usize) -> YulOutput<Vec<u8>> {
+    gas.charge(50000)?;
+    if input.len() != 192 {
+        return failure();
+    }
+    let (versioned_hash, z, y) = (&input[..32], &input[32..64], &input[64..96]);
+    let (commitment, proof) = (&input[96..144], &input[144..]);
+    let mut commitment_hash = Sha256::digest(commitment);
+    commitment_hash[0] = VERSIONED_HASH_VERSION_KZG;
+    if versioned_hash != commitment_hash.as_slice() {
+        return failure();
+    }
+    let settings = c_kzg::ethereum_kzg_settings(0);
+    let is_valid = settings.verify_kzg_proof(
+        &Bytes48::from_bytes(commitment).unwrap(),
+        &Bytes32::from_bytes(z).unwrap(),
+        &Bytes32::from_bytes(y).unwrap(),
+        &Bytes48::from_bytes(proof).unwrap(),
+    );
+    // Also fails on the values which are not field elements or points
+    if !matches!(is_valid, Ok(true)) {
+        return failure();
+    }
+    Ok([
+        FIELD_ELEMENTS_PER_BLOB.to_be_bytes::<32>(),
+        BLS_MODULUS.to_be_bytes(),
+    ]
+    .concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReturnOrRevert;
+
+    fn run(precompile: Precompile, input: &[u8]) -> YulOutput<Vec<u8>> {
+        precompile(input, &mut Gas::metered(1_000_000), 1 << 20)
+    }
+
+    fn concat(words: &[&str]) -> Vec<u8> {
+        words
+            .iter()
+            .flat_map(|word| hex::decode(word).unwrap())
+            .collect()
+    }
+
+    const ONE: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const TWO: &str = "0000000000000000000000000000000000000000000000000000000000000002";
+    /// `p - 2`, for the opposite of the generator `(1, 2)` of G1.
+    const MINUS_TWO: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45";
+    /// The generator of G2, with the imaginary part of each coordinate first.
+    const G2_GENERATOR: [&str; 4] = [
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    ];
+    /// Twice the generator of G1.
+    const DOUBLE: [&str; 2] = [
+        "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3",
+        "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+    ];
+
+    #[test]
+    fn ec_add_and_mul() {
+        let double = concat(&DOUBLE);
+        assert_eq!(run(ec_add, &concat(&[ONE, TWO, ONE, TWO])).unwrap(), double);
+        assert_eq!(run(ec_mul, &concat(&[ONE, TWO, TWO])).unwrap(), double);
+        // The point at infinity is neutral
+        assert_eq!(
+            run(ec_add, &concat(&[ONE, TWO])).unwrap(),
+            concat(&[ONE, TWO])
+        );
+        assert_eq!(
+            run(ec_add, &concat(&[ONE, TWO, ONE, MINUS_TWO])).unwrap(),
+            vec![0; 64]
+        );
+        // Not on the curve
+        assert!(run(ec_add, &concat(&[ONE, ONE])).is_err());
+        assert!(run(ec_mul, &concat(&[TWO, TWO, TWO])).is_err());
+    }
+
+    #[test]
+    fn ec_pairing_check() {
+        fn pair(y: &'static str) -> Vec<&'static str> {
+            [&[ONE, y][..], &G2_GENERATOR].concat()
+        }
+        let success = U256::from(1).to_be_bytes_vec();
+        let failure = U256::ZERO.to_be_bytes_vec();
+        assert_eq!(run(ec_pairing, &[]).unwrap(), success);
+        assert_eq!(
+            run(ec_pairing, &concat(&[pair(TWO), pair(MINUS_TWO)].concat())).unwrap(),
+            success
+        );
+        assert_eq!(run(ec_pairing, &concat(&pair(TWO))).unwrap(), failure);
+        // Coordinates of G2 in the wrong order are not on the curve
+        let mut swapped = pair(TWO);
+        swapped.swap(2, 3);
+        assert!(run(ec_pairing, &concat(&swapped)).is_err());
+        assert!(run(ec_pairing, &[0; 191]).is_err());
+    }
+
+    #[test]
+    fn ripemd160_digest() {
+        assert_eq!(
+            run(ripemd160, b"").unwrap(),
+            concat(&["0000000000000000000000009c1185a5c5e9fc54612808977ee8f548b2258d31"])
+        );
+    }
+
+    /// The test vectors 4 to 7 of EIP-152.
+    #[test]
+    fn blake2f_compression() {
+        let input = |rounds: &str, last: &str| {
+            let h = "48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b";
+            let mut m = hex::decode("616263").unwrap();
+            m.resize(128, 0);
+            let t = "03000000000000000000000000000000";
+            [concat(&[rounds, h]), m, concat(&[t, last])].concat()
+        };
+        let cases = [
+            ("0000000c", "01", "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"),
+            ("0000000c", "00", "75ab69d3190a562c51aef8d88f1c2775876944407270c42c9844252c26d2875298743e7f6d5ea2f2d3e8d226039cd31b4e426ac4f2d3d666a610c2116fde4735"),
+            ("00000001", "01", "b63a380cb2897d521994a85234ee2c181b5f844d2c624c002677e9703449d2fba551b3a8333bcdf5f2f7e08993d53923de3d64fcc68c034e717b9293fed7a421"),
+            ("00000000", "01", "08c9bcf367e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d282e6ad7f520e511f6c3e2b8c68059b9442be0454267ce079217e1319cde05b"),
+        ];
+        for (rounds, last, output) in cases {
+            assert_eq!(
+                run(blake2f, &input(rounds, last)).unwrap(),
+                hex::decode(output).unwrap(),
+                "{rounds} rounds"
+            );
+        }
+        // The final block flag must be 0 or 1
+        assert!(run(blake2f, &input("0000000c", "02")).is_err());
+    }
+
+    #[test]
+    fn modexp_cost_by_words_of_8_bytes() {
+        assert_eq!(modexp_cost(64, 32, 64, U256::MAX), 5440);
+        assert_eq!(modexp_cost(1, 1, 1, U256::from(3)), 200);
+        assert_eq!(modexp_cost(32, 64, 32, U256::MAX), 16 * (8 * 32 + 255) / 3);
+    }
+
+    #[test]
+    fn modexp_lengths_past_the_memory_limit() {
+        let mut input = vec![0u8; 96];
+        input[..32].copy_from_slice(&U256::from(1u64 << 40).to_be_bytes::<32>());
+        input[95] = 1;
+        let mut gas = Gas::unmetered(100_000);
+        assert!(matches!(
+            modexp(&input, &mut gas, 1 << 20),
+            Err(ReturnOrRevert::Halt(Halt::MemoryLimit))
+        ));
+        let mut gas = Gas::metered(100_000);
+        assert!(matches!(
+            modexp(&input, &mut gas, 1 << 20),
+            Err(ReturnOrRevert::Halt(Halt::OutOfGas))
+        ));
+
+        // Only the exponent is long, which costs the minimum and returns nothing
+        let mut input = vec![0u8; 96];
+        input[32..64].copy_from_slice(&U256::from(1u64 << 40).to_be_bytes::<32>());
+        let mut gas = Gas::metered(1_000_000);
+        assert_eq!(modexp(&input, &mut gas, 1 << 20).unwrap(), Vec::<u8>::new());
+        assert_eq!(gas.used(), 200);
+        let mut gas = Gas::unmetered(1_000_000);
+        assert_eq!(modexp(&input, &mut gas, 1 << 20).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn blake2f_rounds_past_the_gas_limit() {
+        let mut input = vec![0u8; 213];
+        input[..4].copy_from_slice(&u32::MAX.to_be_bytes());
+        let mut gas = Gas::unmetered(100_000);
+        assert!(matches!(
+            blake2f(&input, &mut gas, 0),
+            Err(ReturnOrRevert::Halt(Halt::OutOfGas))
+        ));
+    }
+
+    #[test]
+    fn point_evaluation_of_the_zero_polynomial() {
+        // The commitment and the proof of the zero polynomial are the point at infinity
+        let mut infinity = [0u8; 48];
+        infinity[0] = 0xc0;
+        let mut versioned_hash = Sha256::digest(infinity);
+        versioned_hash[0] = VERSIONED_HASH_VERSION_KZG;
+        let input = |y: &str| {
+            [
+                &versioned_hash[..],
+                &concat(&[TWO, y]),
+                &infinity,
+                &infinity,
+            ]
+            .concat()
+        };
+        assert_eq!(
+            run(point_evaluation, &input(&"00".repeat(32))).unwrap(),
+            concat(&[
+                "0000000000000000000000000000000000000000000000000000000000001000",
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+            ])
+        );
+        assert!(run(point_evaluation, &input(ONE)).is_err());
+        let mut wrong_hash = input(&"00".repeat(32));
+        wrong_hash[0] = 0;
+        assert!(run(point_evaluation, &wrong_hash).is_err());
+    }
+}
@@ -0,0 +1,498 @@
+//! Backends of the persistent storage of the accounts, as read by `sload` and written by
+//! `sstore`, and of the immutables of their code, as read by `loadimmutable`.
+
+use crate::world::{from_address, to_address};
+use alloy_primitives::{Address, B256, U256};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The storage of all the accounts, by address and key. As in the EVM, a slot set to zero is
+/// deleted, and a missing slot reads as zero.
+///
+/// It also keeps the immutables of the deployed contracts, by address and name, as they live
+/// as long as the code of the account.
+pub trait Storage: Debug {
+    fn get(&self, address: U256, key: U256) -> U256;
+
+    fn set(&mut self, address: U256, key: U256, value: U256);
+
+    /// The non-zero slots of an account, by key.
+    fn slots(&self, address: U256) -> Vec<(U256, U256)>;
+
+    /// The immutables of the code deployed at an account, by name.
+    fn immutables(&self, address: U256) -> HashMap<U256, U256>;
+
+    /// Replace the immutables of an account, as when deploying its code. An empty map deletes
+    /// them.
+    fn set_immutables(&mut self, address: U256, immutables: HashMap<U256, U256>);
+
+    /// The addresses of the accounts with non-zero slots or immutables.
+    fn addresses(&self) -> Vec<U256>;
+
+    /// Delete all the slots of an account.
+    fn clear(&mut self, address: U256) {
+        for (key, _) in self.slots(address) {
+            self.set(address, key, U256::ZERO);
+        }
+    }
+
+    /// Write the pending changes to where the storage is kept, if it outlives the program.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Storage kept in memory, the default one of a `World`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStorage {
+    pub accounts: HashMap<U256, HashMap<U256, U256>>,
+    pub immutables: HashMap<U256, HashMap<U256, U256>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy all the slots and immutables of another storage.
+    pub fn snapshot(storage: &dyn Storage) -> Self {
+        let mut snapshot = Self::new();
+        for address in storage.addresses() {
+            for (key, value) in storage.slots(address) {
+                snapshot.set(address, key, value);
+            }
+            snapshot.set_immutables(address, storage.immutables(address));
+        }
+        snapshot
+    }
+
+    /// Read the accounts from a JSON object by address, each with an object of slot values by
+    /// key in `storage`, and of immutable values by name in `immutables`.
+    pub(crate) fn from_json(json: &Value) -> io::Result<Self> {
+        let mut storage = Self::new();
+        let accounts = json
+            .as_object()
+            .ok_or_else(|| invalid("expected an object of accounts"))?;
+        for (address, account) in accounts {
+            let address = parse_address(address)
+                .ok_or_else(|| invalid(format!("invalid address {address}")))?;
+            if let Some(slots) = account.get("storage") {
+                for (key, value) in parse_slots(address, slots)? {
+                    storage.set(address, key, value);
+                }
+            }
+            if let Some(immutables) = account.get("immutables") {
+                storage.set_immutables(address, parse_immutables(address, immutables)?);
+            }
+        }
+        Ok(storage)
+    }
+
+    /// The accounts as a JSON object, in the format read by `from_json`.
+    pub(crate) fn to_json(&self) -> Value {
+        let accounts = self
+            .addresses()
+            .into_iter()
+            .map(|address| {
+                let mut account = Map::new();
+                let slots = self.slots(address);
+                if !slots.is_empty() {
+                    let slots = slots
+                        .into_iter()
+                        .map(|(key, value)| (B256::from(key).to_string(), hex_value(value)))
+                        .collect();
+                    account.insert("storage".to_string(), Value::Object(slots));
+                }
+                let immutables = self.immutables(address);
+                if !immutables.is_empty() {
+                    let immutables = immutables
+                        .into_iter()
+                        .map(|(name, value)| (immutable_name(name), hex_value(value)))
+                        .collect();
+                    account.insert("immutables".to_string(), Value::Object(immutables));
+                }
+                (to_address(address).to_string(), Value::Object(account))
+            })
+            .collect();
+        Value::Object(accounts)
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, address: U256, key: U256) -> U256 {
+        self.accounts
+            .get(&address)
+            .and_then(|slots| slots.get(&key).cloned())
+            .unwrap_or_default()
+    }
+
+    fn set(&mut self, address: U256, key: U256, value: U256) {
+        if !value.is_zero() {
+            self.accounts.entry(address).or_default().insert(key, value);
+        } else if let Some(slots) = self.accounts.get_mut(&address) {
+            slots.remove(&key);
+            if slots.is_empty() {
+                self.accounts.remove(&address);
+            }
+        }
+    }
+
+    fn slots(&self, address: U256) -> Vec<(U256, U256)> {
+        self.accounts
+            .get(&address)
+            .map(|slots| slots.iter().map(|(&key, &value)| (key, value)).collect())
+            .unwrap_or_default()
+    }
+
+    fn immutables(&self, address: U256) -> HashMap<U256, U256> {
+        self.immutables.get(&address).cloned().unwrap_or_default()
+    }
+
+    fn set_immutables(&mut self, address: U256, immutables: HashMap<U256, U256>) {
+        if immutables.is_empty() {
+            self.immutables.remove(&address);
+        } else {
+            self.immutables.insert(address, immutables);
+        }
+    }
+
+    fn addresses(&self) -> Vec<U256> {
+        let mut addresses: Vec<_> = self
+            .accounts
+            .keys()
+            .chain(self.immutables.keys())
+            .cloned()
+            .collect();
+        addresses.sort();
+        addresses.dedup();
+        addresses
+    }
+
+    fn clear(&mut self, address: U256) {
+        self.accounts.remove(&address);
+    }
+}
+
+/// Storage kept in a JSON file of accounts by address, in the format of the `alloc` of a
+/// genesis file:
+///
+/// ```json
+/// {
+///   "0x5FbDB2315678afecb367f032d93F642f64180aa3": {
+///     "immutables": { "64": "0x…" },
+///     "storage": {
+///       "0x0000000000000000000000000000000000000000000000000000000000000002": "0x…"
+///     }
+///   }
+/// }
+/// ```
+///
+/// The accounts are loaded in memory by `open`, and written back by `flush`.
+#[derive(Debug)]
+pub struct FileStorage {
+    path: PathBuf,
+    storage: MemoryStorage,
+}
+
+impl FileStorage {
+    /// Load the storage from a file, starting empty if it does not exist yet.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let storage = match std::fs::read_to_string(&path) {
+            Ok(text) => MemoryStorage::from_json(&serde_json::from_str(&text)?)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => MemoryStorage::new(),
+            Err(error) => return Err(error),
+        };
+        Ok(Self { path, storage })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Storage for FileStorage {
+    fn get(&self, address: U256, key: U256) -> U256 {
+        self.storage.get(address, key)
+    }
+
+    fn set(&mut self, address: U256, key: U256, value: U256) {
+        self.storage.set(address, key, value)
+    }
+
+    fn slots(&self, address: U256) -> Vec<(U256, U256)> {
+        self.storage.slots(address)
+    }
+
+    fn immutables(&self, address: U256) -> HashMap<U256, U256> {
+        self.storage.immutables(address)
+    }
+
+    fn set_immutables(&mut self, address: U256, immutables: HashMap<U256, U256>) {
+        self.storage.set_immutables(address, immutables)
+    }
+
+    fn addresses(&self) -> Vec<U256> {
+        self.storage.addresses()
+    }
+
+    fn clear(&mut self, address: U256) {
+        self.storage.clear(address)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.storage.to_json())?;
+        std::fs::write(&self.path, json + "\n")
+    }
+}
+
+/// Storage reading from another one, such as a saved state, but keeping the changes to itself.
+/// Running transactions on a fork leaves its base untouched.
+#[derive(Debug)]
+pub struct ForkStorage {
+    base: Box<dyn Storage>,
+    /// The slots written since the fork, including the ones set to zero.
+    changes: HashMap<(U256, U256), U256>,
+    /// The immutables replaced since the fork, including the deleted ones.
+    immutable_changes: HashMap<U256, HashMap<U256, U256>>,
+}
+
+impl ForkStorage {
+    pub fn new(base: impl Storage + 'static) -> Self {
+        Self {
+            base: Box::new(base),
+            changes: HashMap::new(),
+            immutable_changes: HashMap::new(),
+        }
+    }
+
+    pub fn base(&self) -> &dyn Storage {
+        self.base.as_ref()
+    }
+
+    /// The slots written since the fork, by address and key.
+    pub fn changes(&self) -> &HashMap<(U256, U256), U256> {
+        &self.changes
+    }
+
+    /// Forget the changes, going back to the base.
+    pub fn reset(&mut self) {
+        self.changes.clear();
+        self.immutable_changes.clear();
+    }
+}
+
+impl Storage for ForkStorage {
+    fn get(&self, address: U256, key: U256) -> U256 {
+        match self.changes.get(&(address, key)) {
+            Some(&value) => value,
+            None => self.base.get(address, key),
+        }
+    }
+
+    fn set(&mut self, address: U256, key: U256, value: U256) {
+        self.changes.insert((address, key), value);
+    }
+
+    fn slots(&self, address: U256) -> Vec<(U256, U256)> {
+        let mut slots: HashMap<_, _> = self.base.slots(address).into_iter().collect();
+        for (&(changed_address, key), &value) in &self.changes {
+            if changed_address == address {
+                slots.insert(key, value);
+            }
+        }
+        slots.retain(|_, value| !value.is_zero());
+        slots.into_iter().collect()
+    }
+
+    fn immutables(&self, address: U256) -> HashMap<U256, U256> {
+        match self.immutable_changes.get(&address) {
+            Some(immutables) => immutables.clone(),
+            None => self.base.immutables(address),
+        }
+    }
+
+    fn set_immutables(&mut self, address: U256, immutables: HashMap<U256, U256>) {
+        self.immutable_changes.insert(address, immutables);
+    }
+
+    fn addresses(&self) -> Vec<U256> {
+        let mut addresses = self.base.addresses();
+        addresses.extend(self.changes.keys().map(|&(address, _)| address));
+        addresses.extend(self.immutable_changes.keys());
+        addresses.sort();
+        addresses.dedup();
+        addresses.retain(|&address| {
+            !self.slots(address).is_empty() || !self.immutables(address).is_empty()
+        });
+        addresses
+    }
+}
+
+/// The slots of an account from a JSON object of values by key, both as 32-byte hexadecimal
+/// words as in the state dumps of geth, where the `0x` prefix is optional.
+pub(crate) fn parse_slots(address: U256, slots: &Value) -> io::Result<Vec<(U256, U256)>> {
+    slots
+        .as_object()
+        .ok_or_else(|| invalid(format!("expected an object of slots for {address:#x}")))?
+        .iter()
+        .map(|(key, value)| {
+            let key = parse_word(key).ok_or_else(|| invalid(format!("invalid key {key}")))?;
+            let value = value
+                .as_str()
+                .and_then(parse_word)
+                .ok_or_else(|| invalid(format!("invalid value of the key {key:#x}")))?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// A hexadecimal word of at most 32 bytes, left-padded, with or without its `0x` prefix.
+fn parse_word(word: &str) -> Option<U256> {
+    let word = word.strip_prefix("0x").unwrap_or(word);
+    if word.is_empty() || word.len() > 64 {
+        return None;
+    }
+    U256::from_str_radix(word, 16).ok()
+}
+
+/// The immutables of an account from a JSON object of values by name.
+pub(crate) fn parse_immutables(
+    address: U256,
+    immutables: &Value,
+) -> io::Result<HashMap<U256, U256>> {
+    let invalid_immutables = || invalid(format!("invalid immutables of {address:#x}"));
+    immutables
+        .as_object()
+        .ok_or_else(invalid_immutables)?
+        .iter()
+        .map(|(name, value)| {
+            let name = parse_immutable_name(name).ok_or_else(invalid_immutables)?;
+            let value = as_u256(value).ok_or_else(invalid_immutables)?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+/// The Yul name of an immutable, which `loadimmutable` gets padded to 32 bytes. The names which
+/// are not text are written as their 32 bytes in hexadecimal.
+fn immutable_name(name: U256) -> String {
+    let bytes = B256::from(name);
+    let length = bytes
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |i| i + 1);
+    match std::str::from_utf8(&bytes[..length]) {
+        Ok(text) if !text.is_empty() && !text.contains('\0') && !text.starts_with("0x") => {
+            text.to_string()
+        }
+        _ => bytes.to_string(),
+    }
+}
+
+fn parse_immutable_name(name: &str) -> Option<U256> {
+    if name.starts_with("0x") {
+        return B256::from_str(name).ok().map(Into::into);
+    }
+    (!name.is_empty() && name.len() <= 32).then(|| B256::right_padding_from(name.as_bytes()).into())
+}
+
+fn hex_value(value: U256) -> Value {
+    Value::String(B256::from(value).to_string())
+}
+
+/// A number, or a string holding a decimal or `0x` hexadecimal number.
+pub(crate) fn as_u256(value: &Value) -> Option<U256> {
+    match value {
+        Value::Number(number) => number.as_u64().map(U256::from),
+        Value::String(number) => U256::from_str(number).ok(),
+        _ => None,
+    }
+}
+
+/// Error for a file which is valid JSON but not of the expected shape.
+pub(crate) fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// An address, with or without its `0x` prefix.
+pub(crate) fn parse_address(address: &str) -> Option<U256> {
+    let address = address.strip_prefix("0x").unwrap_or(address);
+    Address::from_str(address).ok().map(from_address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn immutables(entries: &[(&str, u64)]) -> HashMap<U256, U256> {
+        entries
+            .iter()
+            .map(|&(name, value)| (parse_immutable_name(name).unwrap(), U256::from(value)))
+            .collect()
+    }
+
+    #[test]
+    fn file_storage_round_trip() {
+        let path = std::env::temp_dir().join(format!("storage-{}.json", std::process::id()));
+        let (first, second) = (U256::from(0x1000), U256::from(0x2000));
+        let binary_name = B256::repeat_byte(0xff).to_string();
+
+        let mut storage = FileStorage::open(&path).unwrap();
+        assert!(storage.addresses().is_empty());
+        storage.set(first, U256::from(2), U256::MAX);
+        storage.set(first, U256::from(3), U256::from(7));
+        storage.set(first, U256::from(3), U256::ZERO);
+        storage.set_immutables(first, immutables(&[("64", 1), ("65", 2)]));
+        storage.set_immutables(second, immutables(&[("owner", 3)]));
+        storage.set_immutables(second, immutables(&[(&binary_name, 4)]));
+        storage.flush().unwrap();
+
+        let reopened = FileStorage::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(reopened.addresses(), vec![first, second]);
+        assert_eq!(reopened.slots(first), vec![(U256::from(2), U256::MAX)]);
+        assert!(reopened.slots(second).is_empty());
+        assert_eq!(reopened.immutables(first), storage.immutables(first));
+        assert_eq!(reopened.immutables(second), storage.immutables(second));
+    }
+
+    #[test]
+    fn fork_storage_leaves_its_base_untouched() {
+        let address = U256::from(0x1000);
+        let mut base = MemoryStorage::new();
+        base.set(address, U256::from(1), U256::from(10));
+        base.set(address, U256::from(2), U256::from(20));
+        base.set_immutables(address, immutables(&[("64", 1)]));
+
+        let mut fork = ForkStorage::new(base.clone());
+        fork.set(address, U256::from(1), U256::from(11));
+        fork.set(address, U256::from(2), U256::ZERO);
+        fork.set(address, U256::from(3), U256::from(30));
+        fork.set_immutables(address, HashMap::new());
+        assert_eq!(fork.get(address, U256::from(1)), U256::from(11));
+        assert_eq!(fork.get(address, U256::from(2)), U256::ZERO);
+        let mut slots = fork.slots(address);
+        slots.sort();
+        assert_eq!(
+            slots,
+            vec![
+                (U256::from(1), U256::from(11)),
+                (U256::from(3), U256::from(30))
+            ]
+        );
+        assert!(fork.immutables(address).is_empty());
+        assert_eq!(MemoryStorage::snapshot(fork.base()), base);
+
+        fork.clear(address);
+        assert!(fork.addresses().is_empty());
+        assert_eq!(MemoryStorage::snapshot(fork.base()), base);
+
+        fork.reset();
+        assert_eq!(MemoryStorage::snapshot(&fork), base);
+    }
+}
@@ -0,0 +1,1107 @@
+use crate::gas;
+use crate::journal::JournalEntry;
+use crate::precompiles;
+use crate::storage::{MemoryStorage, Storage};
+use crate::{
+    BlockEnv, CallOutcome, Context, ContractInteractions, CreateMessage, CreateScheme, Gas, Halt,
+    Memory, Message, ReturnOrRevert, TxEnv, YulOutput,
+};
+use alloy_primitives::{keccak256, Address, Log, B256, U256};
+use std::collections::{HashMap, HashSet};
+
+/// Entry point of a translated contract, typically a generated `*_deployed::body` function.
+pub type Code<CI> = fn(&mut Context<CI>) -> YulOutput<()>;
+
+#[derive(Debug)]
+pub struct Account<CI> {
+    pub balance: U256,
+    pub nonce: u64,
+    pub code: Option<Code<CI>>,
+    /// Bytes standing for `code`, as read by `codesize` and `codecopy`.
+    pub bytecode: Vec<u8>,
+}
+
+impl<CI> Default for Account<CI> {
+    fn default() -> Self {
+        Self {
+            balance: U256::ZERO,
+            nonce: 0,
+            code: None,
+            bytecode: vec![],
+        }
+    }
+}
+
+/// A translated Yul object, with the bytes standing for it in the EVM.
+#[derive(Debug)]
+pub struct Object<CI> {
+    pub code: Code<CI>,
+    /// The name of the object, followed by its sub-objects and data sections.
+    pub bytes: Vec<u8>,
+    /// Offset and size in `bytes` of the object itself and of its sub-objects, by name, as
+    /// returned by `dataoffset` and `datasize`.
+    pub layout: HashMap<U256, (usize, usize)>,
+}
+
+/// A part of a Yul object following its code.
+#[derive(Clone, Debug)]
+pub enum Section {
+    /// A sub-object, by name. It must have been registered before its parent.
+    Object(U256),
+    Data(Vec<u8>),
+}
+
+/// A storage slot, with its value at the start of the transaction and its current value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StorageSlot {
+    pub original: U256,
+    pub current: U256,
+}
+
+impl StorageSlot {
+    /// Whether the slot differs from its value at the start of the transaction.
+    pub fn is_dirty(&self) -> bool {
+        self.original != self.current
+    }
+}
+
+/// The state of all the accounts, shared by the call frames of a transaction.
+#[derive(Debug)]
+pub struct World<CI> {
+    pub accounts: HashMap<U256, Account<CI>>,
+    /// Persistent storage of the accounts, which the journal reverts like the accounts.
+    pub storage: Box<dyn Storage>,
+    /// Addresses accessed during the current transaction (EIP-2929).
+    pub accessed_addresses: HashSet<U256>,
+    /// Storage slots accessed during the current transaction, by address and key (EIP-2929).
+    pub accessed_storage: HashSet<(U256, U256)>,
+    /// Values at the start of the transaction of the storage slots written since, by address and
+    /// key.
+    pub original_storage: HashMap<(U256, U256), U256>,
+    /// Transient storage by address and key, which only lasts for one transaction (EIP-1153).
+    pub transient_storage: HashMap<(U256, U256), U256>,
+    /// Logs emitted during the current transaction.
+    pub logs: Vec<Log>,
+    /// Contracts created during the current transaction, the only ones that `selfdestruct` can
+    /// delete (EIP-6780).
+    pub created_accounts: HashSet<U256>,
+    /// Contracts to delete at the end of the current transaction.
+    pub selfdestructs: HashSet<U256>,
+    /// Changes made during the current transaction, to undo those of the failing call frames.
+    pub journal: Vec<JournalEntry>,
+    /// Translated objects by name, as found at the start of their bytes.
+    pub objects: HashMap<U256, Object<CI>>,
+}
+
+impl<CI> Default for World<CI> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<CI> World<CI> {
+    pub fn new() -> Self {
+        Self::with_storage(MemoryStorage::new())
+    }
+
+    pub fn with_storage(storage: impl Storage + 'static) -> Self {
+        Self {
+            accounts: HashMap::new(),
+            storage: Box::new(storage),
+            accessed_addresses: HashSet::new(),
+            accessed_storage: HashSet::new(),
+            original_storage: HashMap::new(),
+            transient_storage: HashMap::new(),
+            logs: vec![],
+            created_accounts: HashSet::new(),
+            selfdestructs: HashSet::new(),
+            journal: vec![],
+            objects: HashMap::new(),
+        }
+    }
+
+    /// Reset the state that only lasts for one transaction.
+    pub fn start_transaction(&mut self) {
+        self.accessed_addresses.clear();
+        self.accessed_storage.clear();
+        self.original_storage.clear();
+        self.transient_storage.clear();
+        self.logs.clear();
+        self.created_accounts.clear();
+        self.selfdestructs.clear();
+        self.journal.clear();
+    }
+
+    /// Delete the contracts which ran `selfdestruct` during the transaction, with their code,
+    /// storage and balance.
+    pub fn finish_transaction(&mut self) {
+        for address in self.selfdestructs.drain() {
+            self.accounts.remove(&address);
+            self.storage.clear(address);
+            self.storage.set_immutables(address, HashMap::new());
+        }
+    }
+
+    /// Mark an address as accessed, returning whether it was already warm. Precompiles are
+    /// always warm.
+    pub fn access_address(&mut self, address: U256) -> bool {
+        if self.accessed_addresses.insert(address) {
+            self.journal.push(JournalEntry::AddressAccessed(address));
+            return precompiles::is_precompile(address);
+        }
+        true
+    }
+
+    /// Mark a storage slot as accessed, returning whether it was already warm.
+    pub fn access_storage(&mut self, address: U256, key: U256) -> bool {
+        if self.accessed_storage.insert((address, key)) {
+            self.journal
+                .push(JournalEntry::StorageAccessed { address, key });
+            return false;
+        }
+        true
+    }
+
+    /// Register the `code` of a translated contract at `address`. As it has no bytes of its
+    /// own, the address is taken as its 32 bytes of code, read by `codesize` and `extcodesize`.
+    pub fn deploy(&mut self, address: U256, code: Code<CI>) -> &mut Account<CI> {
+        let account = self.account_mut(address);
+        account.code = Some(code);
+        account.bytecode = address.to_be_bytes_vec();
+        account
+    }
+
+    /// Register the `code` of a translated object, to deploy it with `create`. The `name` is
+    /// the Yul object name, padded to 32 bytes as in `datasize`.
+    pub fn register_object(&mut self, name: U256, code: Code<CI>, sections: Vec<Section>) {
+        let mut bytes = name.to_be_bytes_vec();
+        let mut layout = HashMap::new();
+        for section in sections {
+            match section {
+                Section::Object(child) => {
+                    let child_bytes = &self.objects[&child].bytes;
+                    layout.insert(child, (bytes.len(), child_bytes.len()));
+                    bytes.extend_from_slice(child_bytes);
+                }
+                Section::Data(data) => bytes.extend(data),
+            }
+        }
+        layout.insert(name, (0, bytes.len()));
+        self.objects.insert(
+            name,
+            Object {
+                code,
+                bytes,
+                layout,
+            },
+        );
+    }
+
+    /// Find the translated object whose name starts the given bytes.
+    pub fn object(&self, bytes: &[u8]) -> Option<&Object<CI>> {
+        let name = U256::from_be_slice(bytes.get(..32)?);
+        self.objects.get(&name)
+    }
+
+    pub fn account(&self, address: U256) -> Option<&Account<CI>> {
+        self.accounts.get(&address)
+    }
+
+    /// Get an account, creating it empty if it does not exist yet.
+    pub fn account_mut(&mut self, address: U256) -> &mut Account<CI> {
+        self.accounts.entry(address).or_default()
+    }
+
+    /// Whether an account has no code, no nonce and no balance (EIP-161).
+    pub fn is_empty(&self, address: U256) -> bool {
+        self.account(address).is_none_or(|account| {
+            account.code.is_none() && account.nonce == 0 && account.balance.is_zero()
+        })
+    }
+
+    /// Size of the code of an account, as returned by `extcodesize`.
+    pub fn code_size(&self, address: U256) -> usize {
+        self.account(address)
+            .filter(|account| account.code.is_some())
+            .map_or(0, |account| account.bytecode.len())
+    }
+
+    pub fn balance(&self, address: U256) -> U256 {
+        self.account(address)
+            .map(|account| account.balance)
+            .unwrap_or_default()
+    }
+
+    pub fn set_balance(&mut self, address: U256, balance: U256) {
+        let account = self.account_mut(address);
+        let previous = account.balance;
+        account.balance = balance;
+        self.journal
+            .push(JournalEntry::BalanceChanged { address, previous });
+    }
+
+    /// Move `value` from one account to another. Does nothing and returns `false` if the sender
+    /// does not have enough balance.
+    pub fn transfer(&mut self, from: U256, to: U256, value: U256) -> bool {
+        let Some(remaining) = self.balance(from).checked_sub(value) else {
+            return false;
+        };
+        if !value.is_zero() && from != to {
+            self.set_balance(from, remaining);
+            self.set_balance(to, self.balance(to).saturating_add(value));
+        }
+        true
+    }
+
+    pub fn mark_created(&mut self, address: U256) {
+        if self.created_accounts.insert(address) {
+            self.journal.push(JournalEntry::AccountCreated(address));
+        }
+    }
+
+    /// Send the balance of a contract to the `beneficiary`. Since EIP-6780, the contract is only
+    /// deleted if it was created in the same transaction, in which case sending the balance to
+    /// itself burns it.
+    pub fn selfdestruct(&mut self, address: U256, beneficiary: U256) {
+        self.transfer(address, beneficiary, self.balance(address));
+        if self.created_accounts.contains(&address) {
+            self.set_balance(address, U256::ZERO);
+            if self.selfdestructs.insert(address) {
+                self.journal.push(JournalEntry::SelfDestructed(address));
+            }
+        }
+    }
+
+    pub fn nonce(&self, address: U256) -> u64 {
+        self.account(address)
+            .map(|account| account.nonce)
+            .unwrap_or_default()
+    }
+
+    pub fn set_nonce(&mut self, address: U256, nonce: u64) {
+        let account = self.account_mut(address);
+        let previous = account.nonce;
+        account.nonce = nonce;
+        self.journal
+            .push(JournalEntry::NonceChanged { address, previous });
+    }
+
+    /// Install the code of a newly created contract, with the immutables set by its constructor.
+    pub fn set_code(
+        &mut self,
+        address: U256,
+        code: Code<CI>,
+        bytecode: Vec<u8>,
+        immutables: HashMap<U256, U256>,
+    ) {
+        let account = self.account_mut(address);
+        account.code = Some(code);
+        account.bytecode = bytecode;
+        self.storage.set_immutables(address, immutables);
+        self.journal.push(JournalEntry::CodeDeployed(address));
+    }
+
+    /// Whether a contract cannot be created at this address, because there is already one.
+    pub fn has_collision(&self, address: U256) -> bool {
+        self.account(address)
+            .is_some_and(|account| account.code.is_some() || account.nonce != 0)
+            || !self.storage.slots(address).is_empty()
+    }
+
+    pub fn sload(&self, address: U256, key: U256) -> U256 {
+        self.storage.get(address, key)
+    }
+
+    pub fn sstore(&mut self, address: U256, key: U256, value: U256) {
+        let current = self.sload(address, key);
+        self.original_storage
+            .entry((address, key))
+            .or_insert(current);
+        self.storage.set(address, key, value);
+        self.journal.push(JournalEntry::StorageChanged {
+            address,
+            key,
+            previous: current,
+        });
+    }
+
+    pub fn tload(&self, address: U256, key: U256) -> U256 {
+        self.transient_storage
+            .get(&(address, key))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn tstore(&mut self, address: U256, key: U256, value: U256) {
+        let previous = self.transient_storage.insert((address, key), value);
+        self.journal.push(JournalEntry::TransientStorageChanged {
+            address,
+            key,
+            previous,
+        });
+    }
+
+    pub fn log(&mut self, address: U256, topics: Vec<U256>, data: Vec<u8>) {
+        let topics = topics.into_iter().map(B256::from).collect();
+        self.logs
+            .push(Log::new_unchecked(to_address(address), topics, data.into()));
+        self.journal.push(JournalEntry::LogEmitted);
+    }
+
+    pub fn storage_slot(&self, address: U256, key: U256) -> StorageSlot {
+        let current = self.sload(address, key);
+        StorageSlot {
+            original: self
+                .original_storage
+                .get(&(address, key))
+                .cloned()
+                .unwrap_or(current),
+            current,
+        }
+    }
+
+    /// The storage slots modified since the start of the transaction, by address and key.
+    pub fn dirty_slots(&self) -> impl Iterator<Item = ((U256, U256), StorageSlot)> + '_ {
+        self.original_storage
+            .keys()
+            .map(|&(address, key)| ((address, key), self.storage_slot(address, key)))
+            .filter(|(_, slot)| slot.is_dirty())
+    }
+}
+
+/// Maximal depth of nested message calls.
+pub const CALL_DEPTH_LIMIT: usize = 1024;
+
+pub(crate) fn to_address(address: U256) -> Address {
+    Address::from_word(B256::from(address))
+}
+
+pub(crate) fn from_address(address: Address) -> U256 {
+    address.into_word().into()
+}
+
+/// Address of a contract created with `create`, from the address and nonce of its creator.
+pub fn create_address(caller: U256, nonce: u64) -> U256 {
+    from_address(to_address(caller).create(nonce))
+}
+
+/// Address of a contract created with `create2` (EIP-1014).
+pub fn create2_address(caller: U256, salt: U256, init_code: &[u8]) -> U256 {
+    from_address(to_address(caller).create2(B256::from(salt), keccak256(init_code)))
+}
+
+/// Interactions running message calls against the contracts deployed in the `World` of the
+/// context.
+#[derive(Debug)]
+pub struct WorldInteractions;
+
+impl ContractInteractions for Context<WorldInteractions> {
+    fn call(&mut self, message: Message) -> CallOutcome {
+        self.dispatch_call(message)
+    }
+
+    fn create(&mut self, message: CreateMessage) -> (CallOutcome, Option<U256>) {
+        self.dispatch_create(message)
+    }
+}
+
+impl<CI> Context<CI> {
+    /// Run a message call to the code deployed at `message.code_address` in a new frame, with a
+    /// fresh memory. Calls to accounts without code succeed with an empty output.
+    pub fn dispatch_call(&mut self, message: Message) -> CallOutcome {
+        if self.depth >= CALL_DEPTH_LIMIT {
+            return CallOutcome::Halt(Halt::StackOverflow);
+        }
+        let Some(value) = self.transferred_value(&message) else {
+            return CallOutcome::Revert(vec![]);
+        };
+        let (code, bytecode) = match self.world.account(message.code_address) {
+            Some(Account {
+                code: Some(code),
+                bytecode,
+                ..
+            }) => (*code, bytecode.clone()),
+            _ => {
+                self.world.transfer(message.caller, message.address, value);
+                return CallOutcome::Return(vec![]);
+            }
+        };
+        let immutables = self.world.storage.immutables(message.code_address);
+        let gas = self.call_gas(&message);
+
+        let mut callee = Context {
+            contract_interactions: std::marker::PhantomData,
+            memory: Memory::new(),
+            memory_limit: self.memory_limit,
+            immutables,
+            world: World::new(),
+            code: bytecode,
+            address: message.address,
+            caller: message.caller,
+            callvalue: message.value,
+            gas,
+            calldata: message.input,
+            block: BlockEnv::default(),
+            tx: TxEnv::default(),
+            is_static: message.is_static,
+            returndata: vec![],
+            depth: self.depth + 1,
+        };
+        let checkpoint = self.enter_frame(&mut callee);
+        callee.world.transfer(callee.caller, callee.address, value);
+        let result = code(&mut callee);
+        let outcome = CallOutcome::from_result(result, &callee.memory);
+        self.exit_frame(callee, checkpoint, &outcome);
+        outcome
+    }
+
+    /// Run a message call to a precompile. This does not depend on the contract interactions, as
+    /// precompiles are part of the EVM.
+    pub fn call_precompile(&mut self, message: Message) -> CallOutcome {
+        let Some(precompile) = precompiles::get(message.code_address) else {
+            return CallOutcome::Return(vec![]);
+        };
+        let Some(value) = self.transferred_value(&message) else {
+            return CallOutcome::Revert(vec![]);
+        };
+        let checkpoint = self.world.checkpoint();
+        self.world.transfer(message.caller, message.address, value);
+        let mut gas = self.call_gas(&message);
+        let memory_limit = self
+            .memory_limit
+            .unwrap_or_else(|| gas::max_memory_size(gas.limit));
+        let outcome = match precompile(&message.input, &mut gas, memory_limit) {
+            Ok(output) => CallOutcome::Return(output),
+            Err(ReturnOrRevert::Halt(halt)) => CallOutcome::Halt(halt),
+            Err(_) => unreachable!("precompiles can only halt"),
+        };
+        if !outcome.is_success() {
+            self.world.revert_to(checkpoint);
+        }
+        self.settle_gas(&gas, &outcome);
+        outcome
+    }
+
+    /// Run the constructor of the translated object at the start of `message.init_code` in a
+    /// new frame, and deploy the object it returns. Also returns the address of the new contract
+    /// on success.
+    pub fn dispatch_create(&mut self, message: CreateMessage) -> (CallOutcome, Option<U256>) {
+        if self.depth >= CALL_DEPTH_LIMIT {
+            return (CallOutcome::Halt(Halt::StackOverflow), None);
+        }
+        if self.world.balance(message.caller) < message.value {
+            return (CallOutcome::Revert(vec![]), None);
+        }
+        let nonce = self.world.nonce(message.caller);
+        self.world.set_nonce(message.caller, nonce + 1);
+        let address = match message.scheme {
+            CreateScheme::Create => create_address(message.caller, nonce),
+            CreateScheme::Create2 { salt } => {
+                create2_address(message.caller, salt, &message.init_code)
+            }
+        };
+        self.world.access_address(address);
+        let gas = self.gas.take_call_gas(U256::MAX);
+        let constructor = match self.world.object(&message.init_code) {
+            Some(object) if !self.world.has_collision(address) => object.code,
+            // Failing creations consume all the gas given to them
+            Some(_) => return (CallOutcome::Halt(Halt::CreateCollision), None),
+            None => return (CallOutcome::Halt(Halt::UnknownObject), None),
+        };
+
+        let mut callee = Context {
+            contract_interactions: std::marker::PhantomData,
+            memory: Memory::new(),
+            memory_limit: self.memory_limit,
+            immutables: HashMap::new(),
+            world: World::new(),
+            code: message.init_code,
+            address,
+            caller: message.caller,
+            callvalue: message.value,
+            gas: self.callee_gas(gas),
+            calldata: vec![],
+            block: BlockEnv::default(),
+            tx: TxEnv::default(),
+            is_static: false,
+            returndata: vec![],
+            depth: self.depth + 1,
+        };
+        let checkpoint = self.enter_frame(&mut callee);
+        callee.world.mark_created(address);
+        callee.world.set_nonce(address, 1);
+        callee
+            .world
+            .transfer(message.caller, address, message.value);
+        let result = constructor(&mut callee);
+        let mut outcome = CallOutcome::from_result(result, &callee.memory);
+        if let CallOutcome::Return(code) = &outcome {
+            outcome = match callee.deploy_code(address, code) {
+                Ok(()) => CallOutcome::Return(vec![]),
+                Err(halt) => {
+                    callee.gas.remaining = 0;
+                    CallOutcome::Halt(halt)
+                }
+            };
+        }
+        self.exit_frame(callee, checkpoint, &outcome);
+        let address = outcome.is_success().then_some(address);
+        (outcome, address)
+    }
+
+    /// Pay for and install the code returned by a constructor. An empty code leaves the contract
+    /// without code, as in the EVM.
+    fn deploy_code(&mut self, address: U256, code: &[u8]) -> Result<(), Halt> {
+        let deposit = gas::CODE_DEPOSIT_BYTE.saturating_mul(code.len() as u64);
+        if self.gas.charge(deposit).is_err() {
+            return Err(Halt::OutOfGas);
+        }
+        if !code.is_empty() {
+            let deployed = self.world.object(code).ok_or(Halt::UnknownObject)?.code;
+            let immutables = std::mem::take(&mut self.immutables);
+            self.world
+                .set_code(address, deployed, code.to_vec(), immutables);
+        }
+        Ok(())
+    }
+
+    /// The value moved by a message call, or `None` if the caller cannot afford it. In this case
+    /// the call fails without running.
+    fn transferred_value(&self, message: &Message) -> Option<U256> {
+        if !message.transfers_value() {
+            return Some(U256::ZERO);
+        }
+        (self.world.balance(message.caller) >= message.value).then_some(message.value)
+    }
+
+    /// Take the gas given to a message call, with the stipend of value transfers.
+    fn call_gas(&mut self, message: &Message) -> Gas {
+        let mut gas = self.gas.take_call_gas(message.gas);
+        if message.transfers_value() {
+            gas = gas.saturating_add(gas::CALL_STIPEND);
+        }
+        self.callee_gas(gas)
+    }
+
+    fn callee_gas(&self, limit: u64) -> Gas {
+        if self.gas.metered {
+            Gas::metered(limit)
+        } else {
+            Gas::unmetered(limit)
+        }
+    }
+
+    /// Lend the world and the environment to the `callee`, returning the checkpoint to revert to
+    /// if it fails.
+    fn enter_frame(&mut self, callee: &mut Context<CI>) -> usize {
+        callee.world = std::mem::take(&mut self.world);
+        callee.block = std::mem::take(&mut self.block);
+        callee.tx = std::mem::take(&mut self.tx);
+        callee.world.checkpoint()
+    }
+
+    /// Take back the world from the `callee`, undoing its changes and keeping its gas according
+    /// to the `outcome`.
+    fn exit_frame(&mut self, callee: Context<CI>, checkpoint: usize, outcome: &CallOutcome) {
+        self.world = callee.world;
+        self.block = callee.block;
+        self.tx = callee.tx;
+        if !outcome.is_success() {
+            self.world.revert_to(checkpoint);
+        }
+        self.settle_gas(&callee.gas, outcome);
+    }
+
+    /// Take back the gas left by a callee. Halting consumes all of it, and only successful calls
+    /// keep their refunds.
+    fn settle_gas(&mut self, callee_gas: &Gas, outcome: &CallOutcome) {
+        if self.gas.metered {
+            match outcome {
+                CallOutcome::Return(_) => {
+                    self.gas.remaining += callee_gas.remaining;
+                    self.gas.refunded += callee_gas.refunded;
+                }
+                CallOutcome::Revert(_) => self.gas.remaining += callee_gas.remaining,
+                CallOutcome::Halt(_) => {}
+            }
+        }
+    }
+}
+
+impl CallOutcome {
+    /// Get the outcome of a frame from the result of its code and its final memory.
+    pub fn from_result(result: YulOutput<()>, memory: &Memory) -> Self {
+        let outcome = match result {
+            Ok(()) => Ok(CallOutcome::Return(vec![])),
+            Err(ReturnOrRevert::Return { start, length }) => {
+                memory.load(start, length).map(CallOutcome::Return)
+            }
+            Err(ReturnOrRevert::Revert { start, length }) => {
+                memory.load(start, length).map(CallOutcome::Revert)
+            }
+            Err(ReturnOrRevert::Halt(halt)) => Ok(CallOutcome::Halt(halt)),
+        };
+        outcome.unwrap_or_else(|error| match error {
+            ReturnOrRevert::Halt(halt) => CallOutcome::Halt(halt),
+            _ => unreachable!("loading memory can only halt"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        address, call, calldataload, codecopy, codesize, datacopy, dataoffset, datasize,
+        extcodesize, log1, mload, mstore, return_, revert, selfdestruct, setimmutable, sstore,
+        staticcall, tload, tstore,
+    };
+
+    const OUTER: u64 = 0x1000;
+    const INNER: u64 = 0x2000;
+    const REVERTING: u64 = 0x3000;
+    const RECIPIENT: u64 = 0x4000;
+    const TRANSIENT: u64 = 0x5000;
+    const FACTORY: u64 = 0x6000;
+    const DOOMED: u64 = 0x7000;
+    const BENEFICIARY: u64 = 0x8000;
+
+    type Frame = Context<WorldInteractions>;
+
+    fn u(value: u64) -> U256 {
+        U256::from(value)
+    }
+
+    fn context(world: World<WorldInteractions>, address: u64) -> Frame {
+        Context {
+            contract_interactions: std::marker::PhantomData,
+            memory: Memory::new(),
+            memory_limit: None,
+            immutables: HashMap::new(),
+            world,
+            code: vec![],
+            address: u(address),
+            caller: u(0xca11),
+            callvalue: U256::ZERO,
+            gas: Gas::metered(1_000_000),
+            calldata: vec![],
+            block: BlockEnv::default(),
+            tx: TxEnv::default(),
+            is_static: false,
+            returndata: vec![],
+            depth: 0,
+        }
+    }
+
+    fn call_with_value(address: u64, value: u64, context: &mut Frame) -> YulOutput<U256> {
+        call(
+            U256::MAX,
+            u(address),
+            u(value),
+            u(0),
+            u(0),
+            u(0),
+            u(0),
+            context,
+        )
+    }
+
+    /// Write the value `tag` everywhere, then make a call of `value` to `callee`.
+    fn change_state(tag: u64, callee: u64, value: u64, context: &mut Frame) -> YulOutput<U256> {
+        sstore(u(1), u(tag), context)?;
+        tstore(u(1), u(tag), context)?;
+        log1(u(0), u(0), u(tag), context)?;
+        call_with_value(callee, value, context)
+    }
+
+    /// After the call, store whether it succeeded and the transient value.
+    fn outer(context: &mut Frame) -> YulOutput<()> {
+        let success = change_state(1, INNER, 5, context)?;
+        sstore(u(2), success + u(10), context)?;
+        let value = tload(u(1), context)?;
+        sstore(u(3), value, context)
+    }
+
+    fn inner(context: &mut Frame) -> YulOutput<()> {
+        let success = change_state(2, REVERTING, 3, context)?;
+        sstore(u(2), success + u(10), context)?;
+        let value = tload(u(1), context)?;
+        sstore(u(3), value, context)
+    }
+
+    fn reverting(context: &mut Frame) -> YulOutput<()> {
+        change_state(3, RECIPIENT, 1, context)?;
+        revert(u(0), u(0), context)
+    }
+
+    fn outer_then_revert(context: &mut Frame) -> YulOutput<()> {
+        outer(context)?;
+        revert(u(0), u(0), context)
+    }
+
+    /// Change the transient storage, then store the value it had.
+    fn transient(context: &mut Frame) -> YulOutput<()> {
+        let value = tload(u(1), context)?;
+        tstore(u(1), u(7), context)?;
+        sstore(u(1), value + u(1), context)
+    }
+
+    fn static_transient(context: &mut Frame) -> YulOutput<()> {
+        let success = staticcall(u(100_000), u(TRANSIENT), u(0), u(0), u(0), u(0), context)?;
+        sstore(u(1), success + u(10), context)?;
+        let success = call_with_value(TRANSIENT, 0, context)?;
+        sstore(u(2), success + u(10), context)
+    }
+
+    /// Store the size of the code seen from inside and from outside, and its first word.
+    fn code_sizes(context: &mut Frame) -> YulOutput<()> {
+        let inside = codesize(context)?;
+        let address = address(context)?;
+        let outside = extcodesize(address, context)?;
+        codecopy(u(0), u(0), inside, context)?;
+        let code = mload(u(0), context)?;
+        sstore(u(1), inside, context)?;
+        sstore(u(2), outside, context)?;
+        sstore(u(3), code, context)
+    }
+
+    fn world() -> World<WorldInteractions> {
+        let mut world = World::new();
+        world.deploy(u(OUTER), outer).balance = u(100);
+        world.deploy(u(INNER), inner);
+        world.deploy(u(REVERTING), reverting);
+        world.deploy(u(TRANSIENT), transient);
+        world
+    }
+
+    /// A Yul name, padded to 32 bytes.
+    fn name(name: &str) -> U256 {
+        B256::right_padding_from(name.as_bytes()).into()
+    }
+
+    /// Keep the argument following the init code as an immutable, then return the bytes of the
+    /// deployed object.
+    fn child(context: &mut Frame) -> YulOutput<()> {
+        let size = datasize(name("Child"), context)?;
+        assert_eq!(codesize(context)?, size + u(32));
+        codecopy(u(0), size, u(32), context)?;
+        let argument = mload(u(0), context)?;
+        setimmutable(u(0), name("argument"), argument, context)?;
+        let offset = dataoffset(name("Child_deployed"), context)?;
+        let size = datasize(name("Child_deployed"), context)?;
+        datacopy(u(0), offset, size, context)?;
+        return_(u(0), size, context)
+    }
+
+    fn child_deployed(_context: &mut Frame) -> YulOutput<()> {
+        Ok(())
+    }
+
+    fn factory() -> Frame {
+        let mut world = World::new();
+        world.register_object(name("Child_deployed"), child_deployed, vec![]);
+        world.register_object(
+            name("Child"),
+            child,
+            vec![Section::Object(name("Child_deployed"))],
+        );
+        world.account_mut(u(FACTORY)).balance = u(100);
+        world.set_nonce(u(FACTORY), 1);
+        context(world, FACTORY)
+    }
+
+    fn create_child(
+        scheme: CreateScheme,
+        value: u64,
+        argument: u64,
+        context: &mut Frame,
+    ) -> (CallOutcome, Option<U256>) {
+        let mut init_code = context.world.objects[&name("Child")].bytes.clone();
+        init_code.extend(u(argument).to_be_bytes::<32>());
+        context.dispatch_create(CreateMessage {
+            scheme,
+            caller: context.address,
+            value: u(value),
+            init_code,
+        })
+    }
+
+    /// Write to the storage, then deploy `Doomed_deployed`.
+    fn doomed(context: &mut Frame) -> YulOutput<()> {
+        sstore(u(1), u(1), context)?;
+        let offset = dataoffset(name("Doomed_deployed"), context)?;
+        let size = datasize(name("Doomed_deployed"), context)?;
+        datacopy(u(0), offset, size, context)?;
+        return_(u(0), size, context)
+    }
+
+    /// Send the balance to the address given as calldata.
+    fn doomed_deployed(context: &mut Frame) -> YulOutput<()> {
+        let beneficiary = calldataload(u(0), context)?;
+        selfdestruct(beneficiary, context)
+    }
+
+    /// A call to `DOOMED`, or the address at slot 1, with the `beneficiary` as calldata.
+    fn destroy(beneficiary: U256, static_call: bool, context: &mut Frame) -> YulOutput<U256> {
+        let mut address = sload(context.address, u(1), context);
+        if address.is_zero() {
+            address = u(DOOMED);
+        }
+        mstore(u(0), beneficiary, context)?;
+        if static_call {
+            staticcall(U256::MAX, address, u(0), u(32), u(0), u(0), context)
+        } else {
+            call(U256::MAX, address, u(0), u(0), u(32), u(0), u(0), context)
+        }
+    }
+
+    fn sload(address: U256, key: U256, context: &Frame) -> U256 {
+        context.world.sload(address, key)
+    }
+
+    /// Create a `Doomed` contract with a value of 10, keeping its address at slot 1.
+    fn create_doomed(context: &mut Frame) -> YulOutput<U256> {
+        let init_code = context.world.objects[&name("Doomed")].bytes.clone();
+        let (_, address) = context.dispatch_create(CreateMessage {
+            scheme: CreateScheme::Create,
+            caller: context.address,
+            value: u(10),
+            init_code,
+        });
+        let address = address.unwrap();
+        sstore(u(1), address, context)?;
+        Ok(address)
+    }
+
+    fn create_then_burn(context: &mut Frame) -> YulOutput<()> {
+        let address = create_doomed(context)?;
+        let success = destroy(address, false, context)?;
+        sstore(u(2), success + u(10), context)
+    }
+
+    fn create_then_destroy_in_reverting_frame(context: &mut Frame) -> YulOutput<()> {
+        create_doomed(context)?;
+        let success = call_with_value(REVERTING, 0, context)?;
+        sstore(u(2), success + u(10), context)
+    }
+
+    fn destroy_then_revert(context: &mut Frame) -> YulOutput<()> {
+        // Run at `REVERTING`, which reads the address of the created contract at `FACTORY`
+        let address = sload(u(FACTORY), u(1), context);
+        mstore(u(0), u(BENEFICIARY), context)?;
+        call(U256::MAX, address, u(0), u(0), u(32), u(0), u(0), context)?;
+        revert(u(0), u(0), context)
+    }
+
+    fn static_destroy(context: &mut Frame) -> YulOutput<()> {
+        let success = destroy(u(BENEFICIARY), true, context)?;
+        sstore(u(2), success + u(10), context)
+    }
+
+    fn doomed_world() -> Frame {
+        let mut context = factory();
+        let world = &mut context.world;
+        world.register_object(name("Doomed_deployed"), doomed_deployed, vec![]);
+        world.register_object(
+            name("Doomed"),
+            doomed,
+            vec![Section::Object(name("Doomed_deployed"))],
+        );
+        world.deploy(u(DOOMED), doomed_deployed).balance = u(50);
+        world.storage.set(u(DOOMED), u(2), u(2));
+        // Enough for the caller to go on after a halt consuming all the gas of a call
+        context.gas = Gas::metered(10_000_000);
+        context
+    }
+
+    fn topics(logs: &[Log]) -> Vec<U256> {
+        logs.iter().map(|log| log.topics()[0].into()).collect()
+    }
+
+    #[test]
+    fn reverting_frame_drops_only_its_changes() {
+        let mut context = context(world(), OUTER);
+        let result = context.execute(outer, vec![]);
+        assert!(result.success);
+        let world = &context.world;
+
+        assert_eq!(world.sload(u(OUTER), u(1)), u(1));
+        assert_eq!(world.sload(u(OUTER), u(2)), u(11));
+        assert_eq!(world.sload(u(OUTER), u(3)), u(1));
+        assert_eq!(world.sload(u(INNER), u(1)), u(2));
+        assert_eq!(world.sload(u(INNER), u(2)), u(10));
+        assert_eq!(world.sload(u(INNER), u(3)), u(2));
+        assert_eq!(world.sload(u(REVERTING), u(1)), U256::ZERO);
+
+        // The transient storage is discarded at the end of the transaction
+        for address in [OUTER, INNER, REVERTING] {
+            assert_eq!(world.tload(u(address), u(1)), U256::ZERO);
+        }
+
+        assert_eq!(topics(&result.logs), vec![u(1), u(2)]);
+
+        assert_eq!(world.balance(u(OUTER)), u(95));
+        assert_eq!(world.balance(u(INNER)), u(5));
+        assert_eq!(world.balance(u(REVERTING)), U256::ZERO);
+        assert_eq!(world.balance(u(RECIPIENT)), U256::ZERO);
+    }
+
+    #[test]
+    fn reverting_transaction_drops_all_changes() {
+        let mut context = context(world(), OUTER);
+        let result = context.execute(outer_then_revert, vec![]);
+        assert!(!result.success);
+        let world = &context.world;
+
+        for address in [OUTER, INNER, REVERTING] {
+            assert_eq!(world.sload(u(address), u(1)), U256::ZERO);
+            assert_eq!(world.sload(u(address), u(2)), U256::ZERO);
+            assert_eq!(world.tload(u(address), u(1)), U256::ZERO);
+        }
+        assert!(result.logs.is_empty());
+        assert!(result.state_diff.is_empty());
+        assert_eq!(world.balance(u(OUTER)), u(100));
+        assert_eq!(world.balance(u(INNER)), U256::ZERO);
+    }
+
+    #[test]
+    fn tstore_fails_in_staticcall() {
+        let mut context = context(world(), OUTER);
+        let result = context.execute(static_transient, vec![]);
+        assert!(result.success);
+        let world = &context.world;
+
+        assert_eq!(world.sload(u(OUTER), u(1)), u(10));
+        assert_eq!(world.sload(u(OUTER), u(2)), u(11));
+        assert_eq!(world.sload(u(TRANSIENT), u(1)), u(1));
+        assert_eq!(world.tload(u(TRANSIENT), u(1)), U256::ZERO);
+    }
+
+    #[test]
+    fn selfdestruct_keeps_an_existing_contract() {
+        let mut context = context(doomed_world().world, DOOMED);
+        let result = context.execute(doomed_deployed, u(BENEFICIARY).to_be_bytes_vec());
+        assert!(result.success);
+        let world = &context.world;
+
+        assert!(world.account(u(DOOMED)).unwrap().code.is_some());
+        assert_eq!(world.sload(u(DOOMED), u(2)), u(2));
+        assert_eq!(world.balance(u(DOOMED)), U256::ZERO);
+        assert_eq!(world.balance(u(BENEFICIARY)), u(50));
+    }
+
+    #[test]
+    fn selfdestruct_deletes_a_contract_created_in_the_transaction() {
+        let mut context = doomed_world();
+        let result = context.execute(create_then_burn, vec![]);
+        assert!(result.success);
+        let world = &context.world;
+        let address = world.sload(u(FACTORY), u(1));
+
+        assert_eq!(world.sload(u(FACTORY), u(2)), u(11));
+        assert!(world.account(address).is_none());
+        assert_eq!(world.sload(address, u(1)), U256::ZERO);
+        // Sent to itself, the balance is burned
+        assert_eq!(world.balance(u(FACTORY)), u(90));
+        assert_eq!(world.balance(address), U256::ZERO);
+    }
+
+    #[test]
+    fn selfdestruct_fails_in_staticcall() {
+        let mut context = doomed_world();
+        let result = context.execute(static_destroy, vec![]);
+        assert!(result.success);
+        let world = &context.world;
+
+        assert_eq!(world.sload(u(FACTORY), u(2)), u(10));
+        assert_eq!(world.balance(u(DOOMED)), u(50));
+        assert_eq!(world.balance(u(BENEFICIARY)), U256::ZERO);
+    }
+
+    #[test]
+    fn reverting_frame_drops_its_selfdestruct() {
+        let mut context = doomed_world();
+        context.world.deploy(u(REVERTING), destroy_then_revert);
+        let result = context.execute(create_then_destroy_in_reverting_frame, vec![]);
+        assert!(result.success);
+        let world = &context.world;
+        let address = world.sload(u(FACTORY), u(1));
+
+        assert_eq!(world.sload(u(FACTORY), u(2)), u(10));
+        assert!(world.account(address).unwrap().code.is_some());
+        assert_eq!(world.sload(address, u(1)), u(1));
+        assert_eq!(world.balance(address), u(10));
+        assert_eq!(world.balance(u(BENEFICIARY)), U256::ZERO);
+    }
+
+    #[test]
+    fn deployed_contract_has_the_size_of_its_placeholder_code() {
+        let mut world = World::new();
+        world.deploy(u(INNER), code_sizes);
+        let mut context = context(world, OUTER);
+        let result = context.execute(
+            |context| call_with_value(INNER, 0, context).map(drop),
+            vec![],
+        );
+        assert!(result.success);
+        let world = &context.world;
+
+        assert_eq!(world.sload(u(INNER), u(1)), u(32));
+        assert_eq!(world.sload(u(INNER), u(2)), u(32));
+        assert_eq!(world.sload(u(INNER), u(3)), u(INNER));
+    }
+
+    #[test]
+    fn create_deploys_the_child_object() {
+        let mut context = factory();
+        let (outcome, address) = create_child(CreateScheme::Create, 10, 42, &mut context);
+        assert_eq!(outcome, CallOutcome::Return(vec![]));
+        let address = address.unwrap();
+        assert_eq!(address, create_address(u(FACTORY), 1));
+        let world = &context.world;
+
+        assert_eq!(world.nonce(u(FACTORY)), 2);
+        assert_eq!(world.nonce(address), 1);
+        assert_eq!(world.balance(u(FACTORY)), u(90));
+        assert_eq!(world.balance(address), u(10));
+        assert_eq!(
+            world.account(address).unwrap().bytecode,
+            world.objects[&name("Child_deployed")].bytes
+        );
+        assert_eq!(
+            world.storage.immutables(address),
+            HashMap::from([(name("argument"), u(42))])
+        );
+    }
+
+    #[test]
+    fn create2_at_the_same_address_collides() {
+        let mut context = factory();
+        let salt = u(7);
+        let mut init_code = context.world.objects[&name("Child")].bytes.clone();
+        init_code.extend(u(42).to_be_bytes::<32>());
+
+        let (outcome, address) = create_child(CreateScheme::Create2 { salt }, 0, 42, &mut context);
+        assert!(outcome.is_success());
+        assert_eq!(address, Some(create2_address(u(FACTORY), salt, &init_code)));
+
+        let (outcome, address) = create_child(CreateScheme::Create2 { salt }, 0, 42, &mut context);
+        assert_eq!(outcome, CallOutcome::Halt(Halt::CreateCollision));
+        assert_eq!(address, None);
+        assert_eq!(context.world.nonce(u(FACTORY)), 3);
+    }
+
+    #[test]
+    fn create_with_more_value_than_the_balance_reverts() {
+        let mut context = factory();
+        let (outcome, address) = create_child(CreateScheme::Create, 101, 42, &mut context);
+        assert_eq!(outcome, CallOutcome::Revert(vec![]));
+        assert_eq!(address, None);
+        assert_eq!(context.world.nonce(u(FACTORY)), 1);
+        assert_eq!(context.world.balance(u(FACTORY)), u(100));
+        assert!(context
+            .world
+            .account(create_address(u(FACTORY), 1))
+            .is_none());
+    }
+}
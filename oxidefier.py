@@ -147,22 +147,35 @@ def statement_to_rust(
                 block_to_rust(return_variables, case.get('body')),
             )
             for case in node.get('cases', [])
-            # TODO: handle the default case in a switch
             if case.get('value') != "default"
         ]
+        # The default case, if any, is always the last one
+        default_cases = [
+            block_to_rust(return_variables, case.get('body'))
+            for case in node.get('cases', [])
+            if case.get('value') == "default"
+        ]
         mutated_variables = set()
-        for _, (_, mutated_variables) in cases:
-            mutated_variables.update(mutated_variables)
-
+        for _, (_, case_mutated_variables) in cases:
+            mutated_variables.update(case_mutated_variables)
+        for _, case_mutated_variables in default_cases:
+            mutated_variables.update(case_mutated_variables)
+
+        # With only a default case, we get a plain block
+        branches = [
+            f"if δ == {value} {{\n" +
+            indent(body)
+            for value, (body, _) in cases
+        ] + [
+            "{\n" +
+            indent(body)
+            for body, _ in default_cases
+        ]
 
         return (
             "// switch\n" + \
             f"let δ = {expression};\n" + \
-            "\n} else ".join(
-                f"if δ == {value} {{\n" +
-                indent(body)
-                for value, (body, _) in cases
-            ) + "\n" + \
+            "\n} else ".join(branches) + "\n" + \
             "}",
             mutated_variables
         )
@@ -406,13 +419,64 @@ def top_level_to_rust(node) -> str:
     return f"// Unsupported top-level node type: {node_type}"
 
 
+def object_module_name(name: str) -> str:
+    # The names end with a generated number, we remove it
+    name = re.sub(r'_[0-9]+$', '', name).lower()
+    return re.sub(r'_[0-9]+_deployed$', '_deployed', name)
+
+
+def object_name_to_rust(name: str) -> str:
+    return "from_hex(\"" + name.encode().hex().ljust(64, '0') + "\")"
+
+
+# Register the code of the object and of its sub-objects in the world, for `create`
+def register_to_rust(node) -> str:
+    sub_objects = [
+        child
+        for child in node.get('subObjects', [])
+        if child.get('nodeType') == 'YulObject'
+    ]
+    sections = [
+        "Section::Object(" + object_name_to_rust(child['name']) + ")"
+        if child.get('nodeType') == 'YulObject'
+        else "Section::Data(alloy_primitives::hex!(\"" + child['value'] + "\").to_vec())"
+        for child in node.get('subObjects', [])
+    ]
+    return \
+        "/// Register the code of this object and of its sub-objects, by object name.\n" + \
+        "pub fn register<CI>(world: &mut World<CI>)\n" + \
+        "where\n" + \
+        indent("Context<CI>: ContractInteractions,\n") + \
+        "{\n" + \
+        indent(
+            "".join(
+                object_module_name(child['name']) + "::register(world);\n"
+                for child in sub_objects
+            ) + \
+            "world.register_object(" + object_name_to_rust(node['name']) + ", body, vec![" + \
+            ", ".join(sections) + "]);\n"
+        ) + \
+        "}"
+
+
+# Entry point running the object as a transaction, only for the deployed code as a creation
+# code is run by `create`
+def execute_to_rust() -> str:
+    return \
+        "/// Run `body` as a transaction to the current contract with the given calldata.\n" + \
+        "pub fn execute<CI>(context: &mut Context<CI>, calldata: Vec<u8>) -> ExecutionResult\n" + \
+        "where\n" + \
+        indent("Context<CI>: ContractInteractions,\n") + \
+        "{\n" + \
+        indent("context.execute(body, calldata)\n") + \
+        "}"
+
+
 def object_to_rust(node) -> str:
     node_type = node.get('nodeType')
 
     if node_type == 'YulObject':
-        # The names end with a generated number, we remove it
-        name = re.sub(r'_[0-9]+$', '', node['name']).lower()
-        name = re.sub(r'_[0-9]+_deployed$', '_deployed', name)
+        name = object_module_name(node['name'])
         return \
             "pub mod " + name + " {\n" + \
             indent(
@@ -420,6 +484,13 @@ def object_to_rust(node) -> str:
                 "use evm_opcodes::*;" + "\n" + \
                 "\n" + \
                 object_to_rust(node['code']) + "\n" + \
+                "\n" + \
+                register_to_rust(node) + "\n" + \
+                (
+                    "\n" + execute_to_rust() + "\n"
+                    if node['name'].endswith('_deployed')
+                    else ""
+                ) + \
                 "".join(
                     "\n" +
                     object_to_rust(child) + "\n"
@@ -432,14 +503,329 @@ def object_to_rust(node) -> str:
     elif node_type == 'YulCode':
         return top_level_to_rust(node['block'])
 
-    elif node_type == 'YulData':
-        return "// Data object not expected"
-
     return f"// Unsupported object node type: {node_type}"
 
 
+rust_keywords = {
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+}
+
+
+# Name of an ABI function, error or parameter in Rust, with `context` and `log` kept for the
+# parameters of the bindings
+def abi_name_to_rust(name: str, index: int) -> str:
+    if name == "":
+        return f"arg{index}"
+    if name in rust_keywords or name in ["context", "log"]:
+        return name + "_"
+    return name
+
+
+# Split a type like `uint256[2][]` into `uint256[2]` and the size of the outer array
+def split_array_type(abi_type: str) -> Union[tuple[str, str], None]:
+    match = re.fullmatch(r'(.*)\[([0-9]*)\]', abi_type)
+    return (match.group(1), match.group(2)) if match else None
+
+
+# Canonical type of a parameter, as in the signatures
+def abi_type_to_signature(param, abi_type: Union[str, None] = None) -> str:
+    abi_type = abi_type or param['type']
+    array = split_array_type(abi_type)
+    if array is not None:
+        return abi_type_to_signature(param, array[0]) + "[" + array[1] + "]"
+    if abi_type == "tuple":
+        return "(" + ",".join(
+            abi_type_to_signature(component)
+            for component in param['components']
+        ) + ")"
+    return abi_type
+
+
+def abi_signature(item) -> str:
+    return item['name'] + "(" + ",".join(
+        abi_type_to_signature(param)
+        for param in item['inputs']
+    ) + ")"
+
+
+# Rust type of a parameter, adding the types to import from `alloy_primitives`
+def abi_type_to_rust(param, imports: set[str], abi_type: Union[str, None] = None) -> str:
+    abi_type = abi_type or param['type']
+    array = split_array_type(abi_type)
+    if array is not None:
+        item = abi_type_to_rust(param, imports, array[0])
+        return f"Vec<{item}>" if array[1] == "" else f"[{item}; {array[1]}]"
+    if abi_type == "tuple":
+        return abi_types_to_rust(param['components'], imports)
+    if abi_type == "bool":
+        return "bool"
+    if abi_type == "string":
+        return "String"
+    if abi_type == "function":
+        imports.add("FixedBytes")
+        return "FixedBytes<24>"
+    if re.fullmatch(r'bytes[0-9]+', abi_type):
+        imports.add("FixedBytes")
+        return "FixedBytes<" + abi_type[len("bytes"):] + ">"
+    rust_type = {
+        "address": "Address",
+        "bytes": "Bytes",
+        "int": "I256",
+        "uint": "U256",
+    }[re.sub(r'[0-9]+$', '', abi_type)]
+    imports.add(rust_type)
+    return rust_type
+
+
+def abi_types_to_rust(params, imports: set[str]) -> str:
+    types = [abi_type_to_rust(param, imports) for param in params]
+    return "(" + ", ".join(types) + ("," if len(types) == 1 else "") + ")"
+
+
+# Parameters and their values, as tuples
+def abi_params_to_rust(params) -> tuple[str, str]:
+    names = [abi_name_to_rust(param['name'], index) for index, param in enumerate(params)]
+    return (
+        "(" + ", ".join(names) + ("," if len(names) == 1 else "") + ")",
+        ", ".join(names),
+    )
+
+
+def abi_function_to_rust(
+    item,
+    rust_name: str,
+    error_name: str,
+    execute: str,
+    imports: set[str],
+) -> str:
+    parameters = "".join(
+        ", " + abi_name_to_rust(param['name'], index) + ": " + abi_type_to_rust(param, imports)
+        for index, param in enumerate(item['inputs'])
+    )
+    arguments = abi_params_to_rust(item['inputs'])[0]
+    output_types = abi_types_to_rust(item['outputs'], imports)
+    if len(item['outputs']) == 1:
+        return_type = abi_type_to_rust(item['outputs'][0], imports)
+        map_output = ".map(|(value,)| value)"
+    else:
+        return_type = output_types
+        map_output = ""
+    return \
+        f"/// Call `{abi_signature(item)}` as a transaction.\n" + \
+        f"pub fn {rust_name}<CI>(context: &mut Context<CI>{parameters}) -> Result<{return_type}, abi::Revert<{error_name}>>\n" + \
+        "where\n" + \
+        indent("Context<CI>: ContractInteractions,\n") + \
+        "{\n" + \
+        indent(
+            f"let calldata = abi::encode_call(\"{abi_signature(item)}\", &{arguments});\n" + \
+            f"let result = {execute}(context, calldata);\n" + \
+            f"abi::decode_result::<{output_types}, _>(result){map_output}\n"
+        ) + \
+        "}"
+
+
+def abi_error_variant_to_rust(item, rust_name: str, imports: set[str]) -> str:
+    fields = "".join(
+        abi_name_to_rust(param['name'], index) + ": " + abi_type_to_rust(param, imports) + ",\n"
+        for index, param in enumerate(item['inputs'])
+    )
+    return \
+        f"/// `{abi_signature(item)}`\n" + \
+        rust_name + \
+        (" {\n" + indent(fields) + "}" if fields != "" else "") + \
+        ",\n"
+
+
+def abi_error_decoding_to_rust(item, rust_name: str, imports: set[str]) -> str:
+    pattern, fields = abi_params_to_rust(item['inputs'])
+    types = abi_types_to_rust(item['inputs'], imports)
+    return \
+        f"if let Some({pattern}) = abi::decode_error::<{types}>(\"{abi_signature(item)}\", data) {{\n" + \
+        indent(
+            "return Some(Self::" + rust_name + (" { " + fields + " }" if fields != "" else "") + ");\n"
+        ) + \
+        "}\n"
+
+
+# The indexed arguments of dynamic types are only logged as their hash
+def abi_event_param_type_to_rust(param, imports: set[str]) -> str:
+    if param['indexed'] and (
+        param['type'] in ["bytes", "string", "tuple"] or split_array_type(param['type']) is not None
+    ):
+        imports.add("B256")
+        return "B256"
+    return abi_type_to_rust(param, imports)
+
+
+def abi_event_variant_to_rust(item, rust_name: str, imports: set[str]) -> str:
+    fields = "".join(
+        abi_name_to_rust(param['name'], index) + ": " + \
+            abi_event_param_type_to_rust(param, imports) + ",\n"
+        for index, param in enumerate(item['inputs'])
+    )
+    return \
+        f"/// `{abi_signature(item)}`\n" + \
+        rust_name + \
+        (" {\n" + indent(fields) + "}" if fields != "" else "") + \
+        ",\n"
+
+
+def abi_event_decoding_to_rust(item, rust_name: str, imports: set[str]) -> str:
+    fields = [abi_name_to_rust(param['name'], index) for index, param in enumerate(item['inputs'])]
+    indexed = [
+        (field, param)
+        for field, param in zip(fields, item['inputs'])
+        if param['indexed']
+    ]
+    data = [param for param in item['inputs'] if not param['indexed']]
+    data_pattern = abi_params_to_rust(
+        [param | {'name': field} for field, param in zip(fields, item['inputs']) if not param['indexed']]
+    )[0]
+    return \
+        f"if log.topics().first() == Some(&abi::event_topic(\"{abi_signature(item)}\")) {{\n" + \
+        indent(
+            "".join(
+                f"let {field} = abi::decode_topic::<" + \
+                    abi_event_param_type_to_rust(param, imports) + \
+                    f">(log, {topic})?;\n"
+                for topic, (field, param) in enumerate(indexed, start=1)
+            ) + \
+            (
+                f"let {data_pattern} = abi::decode::<" + abi_types_to_rust(data, imports) + \
+                    ">(&log.data.data)?;\n"
+                if data else ""
+            ) + \
+            "return Some(Self::" + rust_name + \
+                (" { " + ", ".join(fields) + " }" if fields else "") + ");\n"
+        ) + \
+        "}\n"
+
+
+# Overloaded names get a numbered suffix
+def overloaded_names(names: list[str]) -> list[str]:
+    used_names: set[str] = set()
+    rust_names = []
+    for name in names:
+        rust_name = name
+        index = 1
+        while rust_name in used_names:
+            rust_name = name + f"_{index}"
+            index += 1
+        used_names.add(rust_name)
+        rust_names.append(rust_name)
+    return rust_names
+
+
+# Typed calls to the functions of the deployed contract, from its solc ABI
+def abi_to_rust(abi, contract_name: str, execute: str) -> str:
+    error_name = contract_name + "Error"
+    imports: set[str] = set()
+
+    function_items = [item for item in abi if item.get('type') == 'function']
+    functions = [
+        abi_function_to_rust(item, rust_name, error_name, execute, imports)
+        for item, rust_name in zip(
+            function_items,
+            overloaded_names([abi_name_to_rust(item['name'], 0) for item in function_items]),
+        )
+    ]
+
+    errors = [item for item in abi if item.get('type') == 'error']
+    error_names = overloaded_names([item['name'] for item in errors])
+    variants = "".join(
+        abi_error_variant_to_rust(item, rust_name, imports)
+        for item, rust_name in zip(errors, error_names)
+    )
+    decodings = "".join(
+        abi_error_decoding_to_rust(item, rust_name, imports)
+        for item, rust_name in zip(errors, error_names)
+    )
+
+    # Anonymous events have no topic to recognize them
+    events = [
+        item
+        for item in abi
+        if item.get('type') == 'event' and not item.get('anonymous', False)
+    ]
+    event_names = overloaded_names([item['name'] for item in events])
+    event_variants = "".join(
+        abi_event_variant_to_rust(item, rust_name, imports)
+        for item, rust_name in zip(events, event_names)
+    )
+    event_decodings = "".join(
+        abi_event_decoding_to_rust(item, rust_name, imports)
+        for item, rust_name in zip(events, event_names)
+    )
+    # The functions return the custom errors of the contract
+    items = []
+    if functions:
+        items.append(
+            "/// Typed calls to the deployed contract, at the address of the context.\n" + \
+            f"pub struct {contract_name};\n" + \
+            "\n" + \
+            f"impl {contract_name} {{\n" + \
+            indent("\n\n".join(functions)) + "\n" + \
+            "}\n"
+        )
+    if functions or errors:
+        items.append(
+            "/// The custom errors of the contract.\n" + \
+            "#[derive(Clone, Debug, PartialEq, Eq)]\n" + \
+            f"pub enum {error_name} {{\n" + \
+            indent(variants) + \
+            "}\n" + \
+            "\n" + \
+            f"impl abi::CustomError for {error_name} {{\n" + \
+            indent(
+                "fn decode(data: &[u8]) -> Option<Self> {\n" + \
+                indent(decodings + "None\n") + \
+                "}\n"
+            ) + \
+            "}\n"
+        )
+    if events:
+        imports.add("Log")
+        items.append(
+            "/// The events of the contract.\n" + \
+            "#[derive(Clone, Debug, PartialEq, Eq)]\n" + \
+            f"pub enum {contract_name}Event {{\n" + \
+            indent(event_variants) + \
+            "}\n" + \
+            "\n" + \
+            f"impl abi::Event for {contract_name}Event {{\n" + \
+            indent(
+                "fn decode_log(log: &Log) -> Option<Self> {\n" + \
+                indent(event_decodings + "None\n") + \
+                "}\n"
+            ) + \
+            "}\n"
+        )
+    if not items:
+        return ""
+
+    return \
+        (
+            "use alloy_primitives::" + (
+                next(iter(imports)) if len(imports) == 1
+                else "{" + ", ".join(sorted(imports)) + "}"
+            ) + ";\n"
+            if imports else ""
+        ) + \
+        (
+            "use evm_opcodes::{abi, Context, ContractInteractions};\n"
+            if functions
+            else "use evm_opcodes::abi;\n"
+        ) + \
+        "\n" + \
+        "\n".join(items)
+
+
 # Return if the file was not empty
-def file_to_rust(contract_name: str, file_path: Path):
+def file_to_rust(contract_name: str, file_path: Path, abi_path: Union[Path, None]):
     with open(file_path, 'r') as file:
         data = json.load(file)
 
@@ -447,39 +833,79 @@ def file_to_rust(contract_name: str, file_path: Path):
         raise ValueError("The file is empty")
 
     rust_code = object_to_rust(data)
-    first_object_name = data['name'].lower()
 
-    rust_file = """// Generated by Oxidefier
+    lib_file = """// Generated by Oxidefier
 
 #![allow(mixed_script_confusables)]
+#![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(uncommon_codepoints)]
+#![allow(unreachable_code)]
 #![allow(unused_assignments)]
 #![allow(unused_variables)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::type_complexity)]
+#![allow(clippy::while_immutable_condition)]
+
+"""
+    lib_file += rust_code + "\n"
+
+    # Path of the `execute` function of the deployed code, if any
+    entry_point = "::".join([object_module_name(data['name'])] + [
+        object_module_name(child['name'])
+        for child in data.get('subObjects', [])[:1]
+        if child.get('nodeType') == 'YulObject'
+    ] + ["execute"])
+
+    if abi_path is not None:
+        with open(abi_path, 'r') as file:
+            abi = json.load(file)
+        # The contract name in Solidity, without the generated number
+        binding_name = re.sub(r'_[0-9]+$', '', data['name']).replace("_", "")
+        bindings = abi_to_rust(abi, binding_name, entry_point)
+        if bindings != "":
+            lib_file += "\n" + bindings
+
+    main_file = """// Generated by Oxidefier
+
+#![allow(unused_mut)]
+#![allow(unused_variables)]
 
 use alloy_primitives::U256;
 use evm_opcodes::*;
 
-"""
-    rust_file += rust_code
-    rust_file += """
-
 fn main() {
-    let context = Context {
-        contract_interactions: std::marker::PhantomData::<DummyContractInteractions>,
+    // Calls from the contract run against the contracts of the world
+    let mut context = Context {
+        contract_interactions: std::marker::PhantomData::<WorldInteractions>,
         memory: Memory::new(),
+        memory_limit: None,
         immutables: std::collections::HashMap::new(),
-        storage: std::collections::HashMap::new(),
+        world: World::new(),
+        code: vec![],
         address: U256::from(123),
         caller: U256::from(124),
         callvalue: U256::from(12),
-        gas: U256::from(100 * 1000),
-        timestamp: U256::from(1000 * 1000),
+        gas: Gas::unmetered(100 * 1000),
         calldata: vec![],
-        chain_id: U256::from(123456),
+        block: BlockEnv {
+            timestamp: U256::from(1000 * 1000),
+            chain_id: U256::from(123456),
+            ..BlockEnv::default()
+        },
+        tx: TxEnv::default(),
+        is_static: false,
+        returndata: vec![],
+        depth: 0,
     };
 
-    // let result = exp_unit_test::exp_unit_test_deployed::fun_test_Exp(&mut context);
+    // Start from the accounts of a saved state, running the registered objects
+    // REGISTER(&mut context.world);
+    // context.world.load_alloc_file("alloc.json").unwrap();
+
+    // let calldata = vec![];
+    // let result = ENTRY_POINT(&mut context, calldata);
     // println!("result: {:#?}", result);
     // println!("context: {:#?}", context);
 }
@@ -490,16 +916,22 @@ fn main() {
     # Create an "src/" folder in the output folder
     src_folder = output_path / "src"
     src_folder.mkdir(parents=True, exist_ok=True)
-    main_rs_file = src_folder / Path("main.rs")
-    main_rs_file.write_text(rust_file)
+    main_file = main_file.replace("ENTRY_POINT", "::".join([contract_name, entry_point]))
+    main_file = main_file.replace("REGISTER", "::".join([contract_name, entry_point.split("::")[0], "register"]))
+
+    (src_folder / "lib.rs").write_text(lib_file)
+    (src_folder / "main.rs").write_text(main_file)
 
 
 def main():
-    """python oxidefier.py <path_to_yul_json_file> <contract_name>"""
-    file_path = sys.argv[1]
+    """python oxidefier.py <path_to_yul_json_file> <contract_name> [<path_to_abi_json_file>]
+
+    Without an explicit ABI, we use the `abi.json` file next to the Yul JSON if there is one."""
+    file_path = Path(sys.argv[1])
     contract_name = sys.argv[2]
+    abi_path = Path(sys.argv[3]) if len(sys.argv) > 3 else file_path.parent / "abi.json"
 
-    file_to_rust(contract_name, Path(file_path))
+    file_to_rust(contract_name, file_path, abi_path if abi_path.exists() else None)
 
     cargo_toml = f"""[package]
 name = "{contract_name}"